@@ -0,0 +1,219 @@
+use crate::cache::{load_json, save_json};
+use crate::day::{Answer, CaseResult, DynDay, SolveError};
+use crate::{solve_part, Cache, Part, TestReport};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+/// The accepted answers of a day. Parts which haven't been recorded are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordedAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub one: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub two: Option<Answer>,
+}
+
+/// Recorded answers by day.
+pub type Answers = BTreeMap<u8, RecordedAnswers>;
+
+/// Loads the recorded answers from a file. A missing file results in no answers.
+pub fn load_answers(path: &Path) -> io::Result<Answers> {
+    load_json(path)
+}
+
+/// Stores the recorded answers in a file.
+pub fn save_answers(path: &Path, answers: &Answers) -> io::Result<()> {
+    save_json(path, answers)
+}
+
+/// Runs the requested part(s) of a day and records the answers,
+/// replacing previously recorded answers of those parts.
+pub fn record_answers(
+    day: &dyn DynDay,
+    part: Part,
+    input: &str,
+    answers: &mut Answers,
+) -> Result<RecordedAnswers, SolveError> {
+    let (one, two, _, _, _) = day.solve(&part, input)?;
+
+    let recorded = answers.entry(day.day()).or_default();
+    if one.is_some() {
+        recorded.one = one;
+    }
+    if two.is_some() {
+        recorded.two = two;
+    }
+
+    Ok(recorded.clone())
+}
+
+/// Runs the requested part(s) of a day on the real input and compares them to
+/// the recorded answers. Parts without a recorded answer are skipped.
+pub fn verify_day(
+    day: &dyn DynDay,
+    part: Part,
+    input: &str,
+    recorded: &RecordedAnswers,
+) -> TestReport {
+    let mut results = vec![];
+
+    if part != Part::Two {
+        results.push(CaseResult::run(
+            "real input",
+            1,
+            recorded.one.clone(),
+            || solve_part(day, 1, input),
+        ));
+    }
+    if part != Part::One {
+        results.push(CaseResult::run(
+            "real input",
+            2,
+            recorded.two.clone(),
+            || solve_part(day, 2, input),
+        ));
+    }
+
+    TestReport {
+        day: day.day(),
+        results,
+    }
+}
+
+/// Verifies both parts of a day using the cached input and the recorded answers
+/// in the cache. Returns `None` if either of them is missing.
+pub fn verify_cached_day(cache: &Cache, day: &dyn DynDay) -> Option<TestReport> {
+    let input = cache.read_input(day.day()).ok()?;
+    let answers = load_answers(&cache.answers_path()).ok()?;
+
+    Some(verify_day(
+        day,
+        Part::Both,
+        &input,
+        answers.get(&day.day())?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::temp_dir;
+    use crate::{parse_number, DayImpl, Registered, TestCase, TestStatus};
+    use std::fs;
+
+    /// Doubles the number it's given in the first part, and squares it in the second.
+    struct Numbers;
+
+    impl DayImpl<u64> for Numbers {
+        fn test_cases() -> Vec<TestCase> {
+            vec![]
+        }
+
+        fn init(input: &str) -> Result<(Self, u64), SolveError> {
+            Ok((Self, parse_number(input)?))
+        }
+
+        fn one(&self, data: &mut u64) -> Result<Answer, SolveError> {
+            Ok(Answer::Number(*data * 2))
+        }
+
+        fn two(&self, data: &mut u64) -> Result<Answer, SolveError> {
+            Ok(Answer::Number(*data * *data))
+        }
+    }
+
+    static NUMBERS: Registered<Numbers, u64> = Registered::new(4);
+
+    fn statuses(report: &TestReport) -> Vec<(u8, TestStatus)> {
+        report
+            .results
+            .iter()
+            .map(|v| (v.part, v.status()))
+            .collect()
+    }
+
+    #[test]
+    fn records_the_requested_parts() {
+        let mut answers = Answers::new();
+
+        let recorded = record_answers(&NUMBERS, Part::One, "3", &mut answers).unwrap();
+        assert_eq!(recorded.one, Some(Answer::Number(6)));
+        assert_eq!(recorded.two, None);
+
+        // Recording the other part keeps the first one.
+        record_answers(&NUMBERS, Part::Two, "3", &mut answers).unwrap();
+        assert_eq!(
+            answers[&4],
+            RecordedAnswers {
+                one: Some(Answer::Number(6)),
+                two: Some(Answer::Number(9)),
+            }
+        );
+
+        assert!(record_answers(&NUMBERS, Part::Both, "x", &mut answers).is_err());
+        assert_eq!(answers[&4].one, Some(Answer::Number(6)));
+    }
+
+    #[test]
+    fn verifies_recorded_answers() {
+        let mut answers = Answers::new();
+        record_answers(&NUMBERS, Part::Both, "3", &mut answers).unwrap();
+
+        let report = verify_day(&NUMBERS, Part::Both, "3", &answers[&4]);
+        assert_eq!(
+            statuses(&report),
+            [(1, TestStatus::Passed), (2, TestStatus::Passed)]
+        );
+        assert!(report.passed());
+
+        let report = verify_day(&NUMBERS, Part::Both, "x", &answers[&4]);
+        assert!(!report.passed());
+        assert!(report
+            .results
+            .iter()
+            .all(|v| matches!(v.result, Some(Err(_)))));
+
+        let report = verify_day(&NUMBERS, Part::Two, "4", &answers[&4]);
+        assert_eq!(statuses(&report), [(2, TestStatus::Failed)]);
+    }
+
+    #[test]
+    fn skips_parts_without_a_recorded_answer() {
+        let recorded = RecordedAnswers {
+            one: Some(Answer::Number(6)),
+            two: None,
+        };
+
+        let report = verify_day(&NUMBERS, Part::Both, "3", &recorded);
+        assert_eq!(
+            statuses(&report),
+            [(1, TestStatus::Passed), (2, TestStatus::Skipped)]
+        );
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn verifies_cached_days() {
+        let dir = temp_dir("answers-cached");
+        let cache = Cache::new(&dir);
+
+        // Neither the input nor the answers are there yet.
+        assert!(verify_cached_day(&cache, &NUMBERS).is_none());
+
+        cache.write_input(4, "3\n").unwrap();
+        assert!(verify_cached_day(&cache, &NUMBERS).is_none());
+
+        let mut answers = Answers::new();
+        record_answers(&NUMBERS, Part::Both, "3", &mut answers).unwrap();
+        save_answers(&cache.answers_path(), &answers).unwrap();
+        assert_eq!(load_answers(&cache.answers_path()).unwrap(), answers);
+        assert!(verify_cached_day(&cache, &NUMBERS).unwrap().passed());
+
+        cache.write_input(4, "5\n").unwrap();
+        assert!(!verify_cached_day(&cache, &NUMBERS).unwrap().passed());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,540 @@
+use crate::cache::{load_json, save_json};
+use crate::day::{DynDay, SolveError};
+use crate::{dynamic_range_time_format, Part};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Controls how often a day is run while benchmarking.
+#[derive(Debug, Clone)]
+pub struct BenchSettings {
+    /// Runs before measuring, which aren't included in the results.
+    pub warmup: usize,
+    /// The maximum amount of measured runs.
+    pub iterations: Option<usize>,
+    /// Stop starting new runs once this much time has been spent measuring.
+    pub time_budget: Option<Duration>,
+}
+
+impl Default for BenchSettings {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: None,
+            time_budget: Some(Duration::from_secs(3)),
+        }
+    }
+}
+
+/// Statistics over all measured runs of one phase (parsing, part 1 or part 2).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub samples: usize,
+}
+
+impl PhaseStats {
+    /// Calculates the statistics of the samples. Returns None if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let nanos: Vec<f64> = sorted.iter().map(|v| v.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        // Sample standard deviation, as the runs are only a sample of all possible runs.
+        let variance = if n > 1 {
+            nanos.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            // Nearest-rank percentile.
+            p95: sorted[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1],
+            samples: n,
+        })
+    }
+}
+
+/// The statistics of every phase of a benchmarked day.
+/// Parts which weren't benchmarked are None.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    pub day: u8,
+    pub parse: PhaseStats,
+    pub one: Option<PhaseStats>,
+    pub two: Option<PhaseStats>,
+}
+
+impl BenchResult {
+    /// The statistics of every benchmarked phase, along with the phase's name.
+    pub fn phases(&self) -> Vec<(&'static str, &PhaseStats)> {
+        let mut phases = vec![("parse", &self.parse)];
+        if let Some(one) = &self.one {
+            phases.push(("part1", one));
+        }
+        if let Some(two) = &self.two {
+            phases.push(("part2", two));
+        }
+        phases
+    }
+}
+
+/// The stored statistics of a single phase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseBaseline {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+    pub p95_ns: u64,
+    pub samples: usize,
+    /// Whether the baseline was measured using a debug build,
+    /// which isn't comparable to release builds.
+    pub debug: bool,
+}
+
+impl From<&PhaseStats> for PhaseBaseline {
+    fn from(stats: &PhaseStats) -> Self {
+        Self {
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            mean_ns: stats.mean.as_nanos() as u64,
+            stddev_ns: stats.stddev.as_nanos() as u64,
+            p95_ns: stats.p95.as_nanos() as u64,
+            samples: stats.samples,
+            debug: cfg!(debug_assertions),
+        }
+    }
+}
+
+/// Stored benchmark results, by day and then by phase (`parse`, `part1` or `part2`).
+pub type Baseline = BTreeMap<u8, BTreeMap<String, PhaseBaseline>>;
+
+/// Loads the baseline from a file. A missing file results in an empty baseline.
+pub fn load_baseline(path: &Path) -> io::Result<Baseline> {
+    load_json(path)
+}
+
+/// Stores the results in the baseline file, replacing previous results of the same phases.
+pub fn save_baseline(path: &Path, results: &[BenchResult]) -> io::Result<()> {
+    let mut baseline = load_baseline(path)?;
+
+    for result in results {
+        let day = baseline.entry(result.day).or_default();
+        for (phase, stats) in result.phases() {
+            day.insert(phase.to_owned(), stats.into());
+        }
+    }
+
+    save_json(path, &baseline)
+}
+
+/// Compares the median of every phase with the baseline and prints the change.
+/// A phase regressed if its median is more than `threshold` percent slower.
+/// Returns false if any phase regressed.
+pub fn compare_with_baseline(result: &BenchResult, baseline: &Baseline, threshold: f64) -> bool {
+    let mut success = true;
+
+    println!("{}:", "Compared to baseline".green().bold());
+    for (phase, stats) in result.phases() {
+        let name = match phase {
+            "parse" => "Parsing",
+            "part1" => "Part 1",
+            _ => "Part 2",
+        };
+        let stored = match baseline.get(&result.day).and_then(|v| v.get(phase)) {
+            Some(stored) => stored,
+            None => {
+                println!("\t{}: {}", name.green(), "no baseline".bold().yellow());
+                continue;
+            }
+        };
+
+        let before = Duration::from_nanos(stored.median_ns);
+        let change = if stored.median_ns == 0 {
+            0.0
+        } else {
+            (stats.median.as_nanos() as f64 / stored.median_ns as f64 - 1.0) * 100.0
+        };
+        let regressed = change > threshold;
+        success &= !regressed;
+
+        println!(
+            "\t{}: {} -> {} ({}) {}",
+            name.green(),
+            dynamic_range_time_format(&before).bold().blue(),
+            dynamic_range_time_format(&stats.median).bold().blue(),
+            format!("{:+.1}%", change).bold(),
+            if regressed {
+                "REGRESSED".red().bold()
+            } else {
+                "OK".green().bold()
+            }
+        );
+        if stored.debug != cfg!(debug_assertions) {
+            println!(
+                "\t\t{}",
+                "The baseline was measured with a different build profile.".yellow()
+            );
+        }
+    }
+
+    success
+}
+
+/// Runs a day repeatedly and collects statistics on how long each phase took.
+/// Parsing is repeated for every run, and cloning the parsed data for the
+/// parts is not part of the measured time.
+pub fn bench_day(
+    day: &dyn DynDay,
+    part: Part,
+    input: &str,
+    settings: &BenchSettings,
+) -> Result<BenchResult, SolveError> {
+    for _ in 0..settings.warmup {
+        day.solve(&part, input)?;
+    }
+
+    let mut parse_samples = vec![];
+    let mut one_samples = vec![];
+    let mut two_samples = vec![];
+
+    let start = Instant::now();
+    loop {
+        let (_, _, init_t, one_t, two_t) = day.solve(&part, input)?;
+        parse_samples.push(init_t);
+        one_samples.push(one_t);
+        two_samples.push(two_t);
+
+        if let Some(iterations) = settings.iterations {
+            if parse_samples.len() >= iterations {
+                break;
+            }
+        }
+        if let Some(time_budget) = settings.time_budget {
+            if start.elapsed() >= time_budget {
+                break;
+            }
+        }
+        if settings.iterations.is_none() && settings.time_budget.is_none() {
+            break;
+        }
+    }
+
+    Ok(BenchResult {
+        day: day.day(),
+        parse: PhaseStats::from_samples(&parse_samples).unwrap(),
+        one: match part {
+            Part::Two => None,
+            _ => PhaseStats::from_samples(&one_samples),
+        },
+        two: match part {
+            Part::One => None,
+            _ => PhaseStats::from_samples(&two_samples),
+        },
+    })
+}
+
+/// Benchmarks a day and prints the statistics of each phase.
+/// Returns None if the input couldn't be parsed or solved.
+pub fn bench_and_print_day(
+    day: &dyn DynDay,
+    part: Part,
+    input: &str,
+    settings: &BenchSettings,
+) -> Option<BenchResult> {
+    println!("{} Day {}", "Benchmarking".green().bold(), day.day());
+    println!("{}", "-----------------------".green().bold());
+
+    let result = match bench_day(day, part, input, settings) {
+        Ok(result) => result,
+        Err(err) => {
+            crate::print_solve_error(&err);
+            return None;
+        }
+    };
+
+    println!(
+        "{} ({} runs{}):",
+        "Results".green().bold(),
+        result.parse.samples,
+        if cfg!(debug_assertions) {
+            ", DEBUG".red().bold().to_string()
+        } else {
+            String::new()
+        }
+    );
+    for (name, stats) in [
+        ("Parsing", Some(&result.parse)),
+        ("Part 1", result.one.as_ref()),
+        ("Part 2", result.two.as_ref()),
+    ] {
+        if let Some(stats) = stats {
+            println!("\t{}:", name.green());
+            for (label, value) in [
+                ("Min:    ", stats.min),
+                ("Median: ", stats.median),
+                ("Mean:   ", stats.mean),
+                ("Std dev:", stats.stddev),
+                ("P95:    ", stats.p95),
+            ] {
+                println!(
+                    "\t\t{} {}",
+                    label,
+                    dynamic_range_time_format(&value).bold().blue()
+                );
+            }
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::temp_dir;
+    use crate::{Answer, DayImpl, Registered, TestCase};
+    use std::cell::Cell;
+    use std::fs;
+
+    thread_local! {
+        static RUNS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Counts how often its input was parsed, and fails to parse `fail`.
+    struct Counter;
+
+    impl DayImpl<()> for Counter {
+        fn test_cases() -> Vec<TestCase> {
+            vec![]
+        }
+
+        fn init(input: &str) -> Result<(Self, ()), SolveError> {
+            if input == "fail" {
+                return Err(SolveError::parse("bad input"));
+            }
+            RUNS.with(|v| v.set(v.get() + 1));
+            Ok((Self, ()))
+        }
+
+        fn one(&self, _data: &mut ()) -> Result<Answer, SolveError> {
+            Ok(Answer::Number(1))
+        }
+
+        fn two(&self, _data: &mut ()) -> Result<Answer, SolveError> {
+            Ok(Answer::Number(2))
+        }
+    }
+
+    static COUNTER: Registered<Counter, ()> = Registered::new(3);
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    /// Benchmarks the counter, returning the result and how often the input was parsed.
+    fn bench(part: Part, settings: BenchSettings) -> (BenchResult, usize) {
+        RUNS.with(|v| v.set(0));
+        let result = bench_day(&COUNTER, part, "input", &settings).unwrap();
+        (result, RUNS.with(Cell::get))
+    }
+
+    #[test]
+    fn calculates_statistics() {
+        assert_eq!(PhaseStats::from_samples(&[]), None);
+
+        let odd = PhaseStats::from_samples(&millis(&[9, 1, 5])).unwrap();
+        assert_eq!(odd.min, Duration::from_millis(1));
+        assert_eq!(odd.median, Duration::from_millis(5));
+        assert_eq!(odd.mean, Duration::from_millis(5));
+        assert_eq!(odd.stddev, Duration::from_millis(4));
+        assert_eq!(odd.p95, Duration::from_millis(9));
+        assert_eq!(odd.samples, 3);
+
+        let even = PhaseStats::from_samples(&millis(&[4, 1, 3, 2])).unwrap();
+        assert_eq!(even.median, Duration::from_micros(2500));
+        assert_eq!(even.mean, Duration::from_micros(2500));
+        // The sample variance is 5/3 ms², not the population variance of 5/4 ms².
+        assert_eq!(even.stddev, Duration::from_nanos(1_290_994));
+
+        let single = PhaseStats::from_samples(&millis(&[7])).unwrap();
+        assert_eq!(single.median, Duration::from_millis(7));
+        assert_eq!(single.stddev, Duration::ZERO);
+        assert_eq!(single.p95, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn uses_the_nearest_rank_for_p95() {
+        let samples: Vec<u64> = (1..=20).collect();
+        let stats = PhaseStats::from_samples(&millis(&samples)).unwrap();
+        assert_eq!(stats.p95, Duration::from_millis(19));
+
+        let samples: Vec<u64> = (1..=21).collect();
+        let stats = PhaseStats::from_samples(&millis(&samples)).unwrap();
+        assert_eq!(stats.p95, Duration::from_millis(20));
+    }
+
+    #[test]
+    fn runs_the_requested_iterations() {
+        let settings = BenchSettings {
+            warmup: 2,
+            iterations: Some(5),
+            time_budget: None,
+        };
+        let (result, runs) = bench(Part::Both, settings);
+
+        assert_eq!(runs, 7);
+        assert_eq!(result.day, 3);
+        assert_eq!(result.parse.samples, 5);
+        assert_eq!(result.one.unwrap().samples, 5);
+        assert_eq!(result.two.unwrap().samples, 5);
+    }
+
+    #[test]
+    fn stops_at_the_time_budget() {
+        let settings = BenchSettings {
+            warmup: 0,
+            iterations: Some(1000),
+            time_budget: Some(Duration::ZERO),
+        };
+        let (result, runs) = bench(Part::One, settings);
+
+        assert_eq!(runs, 1);
+        assert_eq!(result.parse.samples, 1);
+        assert!(result.one.is_some());
+        assert!(result.two.is_none());
+
+        let settings = BenchSettings {
+            warmup: 1,
+            iterations: None,
+            time_budget: Some(Duration::from_millis(20)),
+        };
+        let start = Instant::now();
+        let (result, runs) = bench(Part::Two, settings);
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(runs, result.parse.samples + 1);
+        assert!(result.one.is_none());
+    }
+
+    #[test]
+    fn runs_once_without_limits() {
+        let settings = BenchSettings {
+            warmup: 0,
+            iterations: None,
+            time_budget: None,
+        };
+        let (result, runs) = bench(Part::Both, settings);
+
+        assert_eq!(runs, 1);
+        assert_eq!(result.parse.samples, 1);
+    }
+
+    #[test]
+    fn stops_at_errors() {
+        let result = bench_day(&COUNTER, Part::Both, "fail", &BenchSettings::default());
+        assert!(result.is_err());
+    }
+
+    /// A result whose phases all have the same median.
+    fn result(day: u8, median_ms: u64, two: bool) -> BenchResult {
+        let stats = PhaseStats::from_samples(&millis(&[median_ms])).unwrap();
+        BenchResult {
+            day,
+            parse: stats.clone(),
+            one: Some(stats.clone()),
+            two: two.then_some(stats),
+        }
+    }
+
+    #[test]
+    fn saves_and_compares_baselines() {
+        let dir = temp_dir("bench-baseline");
+        let path = dir.join("nested").join("baseline.json");
+
+        assert_eq!(load_baseline(&path).unwrap(), Baseline::new());
+        save_baseline(&path, &[result(1, 10, true), result(2, 10, false)]).unwrap();
+        let baseline = load_baseline(&path).unwrap();
+
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline[&1].len(), 3);
+        assert_eq!(baseline[&1]["part2"].median_ns, 10_000_000);
+        assert_eq!(baseline[&1]["part2"].debug, cfg!(debug_assertions));
+
+        assert!(compare_with_baseline(&result(1, 5, true), &baseline, 10.0));
+        assert!(compare_with_baseline(&result(1, 11, true), &baseline, 15.0));
+        assert!(!compare_with_baseline(
+            &result(1, 12, true),
+            &baseline,
+            15.0
+        ));
+        assert!(compare_with_baseline(&result(1, 12, true), &baseline, 25.0));
+
+        // Phases and days without a baseline are reported, but don't fail the comparison.
+        assert!(compare_with_baseline(&result(2, 10, true), &baseline, 10.0));
+        assert!(compare_with_baseline(&result(3, 50, true), &baseline, 10.0));
+
+        // Saving again replaces the stored phases and keeps the others.
+        save_baseline(&path, &[result(2, 20, true)]).unwrap();
+        let baseline = load_baseline(&path).unwrap();
+
+        assert_eq!(baseline[&1]["parse"].median_ns, 10_000_000);
+        assert_eq!(baseline[&2]["parse"].median_ns, 20_000_000);
+        assert_eq!(baseline[&2].len(), 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn ignores_zero_baselines() {
+        let zero = PhaseStats::from_samples(&[Duration::ZERO]).unwrap();
+        let baseline = Baseline::from([(
+            1,
+            BTreeMap::from([("parse".to_owned(), PhaseBaseline::from(&zero))]),
+        )]);
+
+        assert!(compare_with_baseline(
+            &result(1, 100, false),
+            &baseline,
+            0.0
+        ));
+    }
+
+    #[test]
+    fn rejects_corrupted_baselines() {
+        let dir = temp_dir("bench-corrupted");
+        let path = dir.join("baseline.json");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "{").unwrap();
+
+        assert!(load_baseline(&path).is_err());
+        assert!(save_baseline(&path, &[result(1, 10, true)]).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
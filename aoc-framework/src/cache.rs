@@ -0,0 +1,518 @@
+use crate::DEFAULT_YEAR;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// The environment variable which overrides the cache directory.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Where the cache used to be. If it exists in the working directory, it's
+/// moved to the cache directory, see [`migrate_legacy_cache`].
+pub const LEGACY_CACHE_DIR: &str = "./.aoc23_cache";
+
+/// The default cache directory: `aoc23` in `$XDG_CACHE_HOME`, `~/.cache` or, on
+/// Windows, `%LOCALAPPDATA%`. Only if none of them is set, `./.aoc23_cache` is used.
+pub fn default_cache_dir() -> PathBuf {
+    let non_empty = |var: &str| {
+        env::var_os(var)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = if cfg!(windows) {
+        non_empty("LOCALAPPDATA")
+    } else {
+        non_empty("XDG_CACHE_HOME").or_else(|| non_empty("HOME").map(|home| home.join(".cache")))
+    };
+
+    match base {
+        Some(base) => base.join("aoc23"),
+        None => PathBuf::from(LEGACY_CACHE_DIR),
+    }
+}
+
+/// Moves a cache from the legacy location to `dir`, unless `dir` already exists.
+/// Returns whether it was moved. Nothing happens if there is no legacy cache,
+/// or if it's the cache directory itself.
+pub fn migrate_legacy_cache(legacy: &Path, dir: &Path) -> io::Result<bool> {
+    if !legacy.is_dir() || legacy == dir {
+        return Ok(false);
+    }
+    if dir.exists() {
+        if fs::canonicalize(legacy)? == fs::canonicalize(dir)? {
+            return Ok(false);
+        }
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    if let Some(parent) = dir.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(legacy, dir)?;
+    Ok(true)
+}
+
+/// The state of a cached input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// The input matches its checksum.
+    Valid,
+    /// The input was cached without a checksum, but looks like an input.
+    Unchecked,
+    /// The input doesn't match its checksum or doesn't look like an input.
+    Corrupted,
+}
+
+impl std::fmt::Display for CacheStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Valid => write!(f, "valid"),
+            Self::Unchecked => write!(f, "no checksum"),
+            Self::Corrupted => write!(f, "corrupted"),
+        }
+    }
+}
+
+/// A cached input, as listed by [`Cache::entries`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheEntry {
+    pub day: u8,
+    /// The size of the input in bytes.
+    pub size: u64,
+    pub status: CacheStatus,
+}
+
+/// The directory inputs, puzzle descriptions, recorded answers, the submission history
+/// and benchmark baselines are stored in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The cache of an event's inputs and answers. Events other than 2023 use a
+    /// subdirectory named after their year, 2023 keeps using the cache itself,
+    /// where its files were stored before other events were supported.
+    pub fn for_year(&self, year: u16) -> Self {
+        if year == DEFAULT_YEAR {
+            self.clone()
+        } else {
+            Self::new(self.dir.join(year.to_string()))
+        }
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("input{:02}.txt", day))
+    }
+
+    pub fn puzzle_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("puzzle{:02}.html", day))
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        self.dir.join("answers.json")
+    }
+
+    pub fn history_path(&self) -> PathBuf {
+        self.dir.join("submissions.json")
+    }
+
+    pub fn baseline_path(&self) -> PathBuf {
+        self.dir.join("bench_baseline.json")
+    }
+
+    /// Reads the cached input of a day, see [`read_cached_input`].
+    pub fn read_input(&self, day: u8) -> io::Result<String> {
+        read_cached_input(&self.input_path(day))
+    }
+
+    /// Caches the input of a day, see [`write_cached_input`].
+    pub fn write_input(&self, day: u8, input: &str) -> io::Result<()> {
+        write_cached_input(&self.input_path(day), input)
+    }
+
+    /// Removes the cached input of a day, if there is one.
+    /// Returns whether there was one.
+    pub fn remove_input(&self, day: u8) -> io::Result<bool> {
+        match remove_cached_input(&self.input_path(day)) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Reads the cached page of a day's puzzle.
+    pub fn read_puzzle(&self, day: u8) -> io::Result<String> {
+        fs::read_to_string(self.puzzle_path(day))
+    }
+
+    /// Caches the page of a day's puzzle.
+    pub fn write_puzzle(&self, day: u8, html: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        write_atomic(&self.puzzle_path(day), html, None)
+    }
+
+    /// Removes the cached page of a day's puzzle, if there is one.
+    /// Returns whether there was one.
+    pub fn remove_puzzle(&self, day: u8) -> io::Result<bool> {
+        match fs::remove_file(self.puzzle_path(day)) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Lists the cached inputs of all days.
+    pub fn entries(&self) -> Vec<CacheEntry> {
+        (1..=25)
+            .filter_map(|day| {
+                let path = self.input_path(day);
+                let size = fs::metadata(&path).ok()?.len();
+                let status = match read_cached_input(&path) {
+                    Ok(_) if checksum_path(&path).exists() => CacheStatus::Valid,
+                    Ok(_) => CacheStatus::Unchecked,
+                    Err(_) => CacheStatus::Corrupted,
+                };
+                Some(CacheEntry { day, size, status })
+            })
+            .collect()
+    }
+}
+
+/// Why a downloaded input was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidInput {
+    /// The input is empty or only whitespace.
+    Empty,
+    /// The input is a HTML page, most likely an error page.
+    Html,
+    /// The input is the notice to not request inputs before they unlock.
+    RequestedEarly,
+}
+
+impl std::fmt::Display for InvalidInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the input is empty"),
+            Self::Html => write!(f, "the input is a HTML page"),
+            Self::RequestedEarly => write!(
+                f,
+                "the input is the notice to not request it before it unlocks"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidInput {}
+
+/// Checks that an input looks like an actual puzzle input.
+pub fn validate_input(input: &str) -> Result<(), InvalidInput> {
+    let trimmed = input.trim_start();
+    if trimmed.is_empty() {
+        return Err(InvalidInput::Empty);
+    }
+    if input.contains("Please don't repeatedly request this endpoint") {
+        return Err(InvalidInput::RequestedEarly);
+    }
+
+    let start = trimmed
+        .get(..trimmed.len().min(64))
+        .unwrap_or(trimmed)
+        .to_ascii_lowercase();
+    if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        return Err(InvalidInput::Html);
+    }
+
+    Ok(())
+}
+
+/// A checksum of an input, to detect corrupted caches. This is the 64 bit FNV-1a hash in hex.
+pub fn input_checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// The file the checksum of a cached input is stored in.
+fn checksum_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_owned();
+    name.push(".checksum");
+    path.with_file_name(name)
+}
+
+/// Writes a file by writing a temporary file next to it and renaming it,
+/// so that an interrupted write never leaves a partial file behind.
+/// `restrict` can adjust how the temporary file is created, e.g. its permissions,
+/// so that the content is never accessible with the default ones.
+pub(crate) fn write_atomic(
+    path: &Path,
+    content: &str,
+    restrict: Option<fn(&mut fs::OpenOptions)>,
+) -> io::Result<()> {
+    let mut name = std::ffi::OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(".tmp");
+    let temp = path.with_file_name(name);
+    // A leftover temporary file might have been created with other permissions.
+    let _ = fs::remove_file(&temp);
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    if let Some(restrict) = restrict {
+        restrict(&mut options);
+    }
+
+    options.open(&temp)?.write_all(content.as_bytes())?;
+    fs::rename(&temp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp);
+    })
+}
+
+/// Loads a JSON file, like the recorded answers. A missing file results in the default value.
+pub(crate) fn load_json<T>(path: &Path) -> io::Result<T>
+where
+    T: DeserializeOwned + Default,
+{
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content).map_err(io::Error::other),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Stores a value as a JSON file, creating its directory if needed.
+/// The file is replaced atomically, so an interrupted write keeps the previous content.
+pub(crate) fn save_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(
+        path,
+        &serde_json::to_string_pretty(value).map_err(io::Error::other)?,
+        None,
+    )
+}
+
+/// Reads a cached input. Inputs which don't match their checksum or don't look like
+/// an input result in an error of kind `InvalidData`, and should be downloaded again.
+/// Inputs cached before checksums were stored are only checked for their content.
+pub fn read_cached_input(path: &Path) -> io::Result<String> {
+    let input = fs::read_to_string(path)?;
+
+    match fs::read_to_string(checksum_path(path)) {
+        Ok(checksum) if checksum.trim() != input_checksum(&input) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the input doesn't match its checksum",
+            ))
+        }
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+    validate_input(&input).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    Ok(input)
+}
+
+/// Validates and caches an input along with its checksum.
+pub fn write_cached_input(path: &Path, input: &str) -> io::Result<()> {
+    validate_input(input).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(path, input, None)?;
+    write_atomic(&checksum_path(path), &input_checksum(input), None)
+}
+
+/// Removes a cached input and its checksum.
+pub fn remove_cached_input(path: &Path) -> io::Result<()> {
+    match fs::remove_file(checksum_path(path)) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+    fs::remove_file(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::temp_dir;
+
+    #[test]
+    fn validates_inputs() {
+        assert_eq!(validate_input("1abc2\npqr3stu8vwx\n"), Ok(()));
+        assert_eq!(validate_input("<>^v\n"), Ok(()));
+        assert_eq!(validate_input(""), Err(InvalidInput::Empty));
+        assert_eq!(validate_input(" \n\n"), Err(InvalidInput::Empty));
+        assert_eq!(
+            validate_input("<!DOCTYPE html>\n<html><body>500</body></html>"),
+            Err(InvalidInput::Html)
+        );
+        assert_eq!(
+            validate_input("\n<html lang=\"en-us\">"),
+            Err(InvalidInput::Html)
+        );
+        assert_eq!(
+            validate_input("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"),
+            Err(InvalidInput::RequestedEarly)
+        );
+    }
+
+    #[test]
+    fn migrates_the_legacy_cache() {
+        let dir = temp_dir("cache-legacy");
+        let legacy = dir.join(".aoc23_cache");
+        let cache = dir.join("xdg").join("aoc23");
+
+        assert!(!migrate_legacy_cache(&legacy, &cache).unwrap());
+
+        Cache::new(&legacy).write_input(1, "1abc2\n").unwrap();
+        assert!(!migrate_legacy_cache(&legacy, &legacy).unwrap());
+        assert!(migrate_legacy_cache(&legacy, &cache).unwrap());
+        assert!(!legacy.exists());
+        assert_eq!(Cache::new(&cache).read_input(1).unwrap(), "1abc2\n");
+
+        // An existing cache is never replaced.
+        Cache::new(&legacy).write_input(2, "1\n").unwrap();
+        assert_eq!(
+            migrate_legacy_cache(&legacy, &cache).unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        assert!(legacy.join("input02.txt").exists());
+        assert!(!cache.join("input02.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn stores_json_atomically() {
+        let dir = temp_dir("cache-json");
+        let path = dir.join("nested").join("answers.json");
+
+        assert_eq!(load_json::<Vec<u8>>(&path).unwrap(), Vec::<u8>::new());
+        save_json(&path, &vec![1_u8, 2]).unwrap();
+        save_json(&path, &vec![3_u8]).unwrap();
+        assert_eq!(load_json::<Vec<u8>>(&path).unwrap(), vec![3]);
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+
+        fs::write(&path, "[3").unwrap();
+        assert!(load_json::<Vec<u8>>(&path).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn caches_inputs_with_checksum() {
+        let dir = temp_dir("cache-roundtrip");
+        let path = dir.join("input01.txt");
+
+        write_cached_input(&path, "1abc2\n").unwrap();
+        assert_eq!(read_cached_input(&path).unwrap(), "1abc2\n");
+        assert_eq!(
+            fs::read_to_string(checksum_path(&path)).unwrap(),
+            input_checksum("1abc2\n")
+        );
+        // Only the input and its checksum are left behind.
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        remove_cached_input(&path).unwrap();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn detects_corrupted_caches() {
+        let dir = temp_dir("cache-corrupted");
+        let path = dir.join("input01.txt");
+
+        write_cached_input(&path, "1abc2\n").unwrap();
+        fs::write(&path, "1abc").unwrap();
+        assert_eq!(
+            read_cached_input(&path).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+
+        // Caches without a checksum are still checked for their content.
+        fs::remove_file(checksum_path(&path)).unwrap();
+        assert_eq!(read_cached_input(&path).unwrap(), "1abc");
+        fs::write(&path, "<html><body>Internal Server Error</body></html>").unwrap();
+        assert_eq!(
+            read_cached_input(&path).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn lists_cached_inputs() {
+        let dir = temp_dir("cache-entries");
+        let cache = Cache::new(&dir);
+
+        cache.write_input(3, "467..114..\n").unwrap();
+        cache.write_input(7, "32T3K 765\n").unwrap();
+        fs::write(cache.input_path(7), "32T3K").unwrap();
+        fs::write(cache.input_path(12), "???.### 1,1,3\n").unwrap();
+
+        assert_eq!(
+            cache.entries(),
+            vec![
+                CacheEntry {
+                    day: 3,
+                    size: 11,
+                    status: CacheStatus::Valid
+                },
+                CacheEntry {
+                    day: 7,
+                    size: 5,
+                    status: CacheStatus::Corrupted
+                },
+                CacheEntry {
+                    day: 12,
+                    size: 14,
+                    status: CacheStatus::Unchecked
+                },
+            ]
+        );
+
+        assert!(cache.remove_input(7).unwrap());
+        assert!(!cache.remove_input(7).unwrap());
+        assert_eq!(cache.entries().len(), 2);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn refuses_to_cache_invalid_inputs() {
+        let dir = temp_dir("cache-invalid");
+        let path = dir.join("input01.txt");
+
+        assert!(write_cached_input(&path, "").is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn separates_events() {
+        let cache = Cache::new("/tmp/aoc23");
+
+        assert_eq!(cache.for_year(2023), cache);
+        assert_eq!(
+            cache.for_year(2021).input_path(1),
+            Path::new("/tmp/aoc23/2021/input01.txt")
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::day::{DynDay, Event, PartStatus};
+use colored::*;
+
+/// A day as shown in the calendar: its metadata and the stars earned for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarDay {
+    pub day: u8,
+    pub title: Option<&'static str>,
+    pub tags: &'static [&'static str],
+    /// The status of each part. The last day only has one.
+    pub parts: Vec<PartStatus>,
+    pub stars: u8,
+}
+
+impl CalendarDay {
+    fn new(day: &dyn DynDay) -> Self {
+        let parts: Vec<PartStatus> = if day.has_part_two() {
+            vec![day.status(1), day.status(2)]
+        } else {
+            vec![day.status(1)]
+        };
+
+        Self {
+            day: day.day(),
+            title: day.title(),
+            tags: day.tags(),
+            stars: parts.iter().filter(|p| **p == PartStatus::Solved).count() as u8,
+            parts,
+        }
+    }
+
+    fn solved(&self) -> bool {
+        self.parts.iter().all(|p| *p == PartStatus::Solved)
+    }
+}
+
+/// Awards the second star of days without a second part, which is
+/// earned once every other part is solved, like on the website.
+fn award_last_stars(days: &mut [CalendarDay]) {
+    if days.iter().all(CalendarDay::solved) {
+        for day in days.iter_mut().filter(|d| d.parts.len() == 1) {
+            day.stars += 1;
+        }
+    }
+}
+
+/// The calendar of all days of an event.
+pub fn calendar(event: &Event) -> Vec<CalendarDay> {
+    let mut days: Vec<CalendarDay> = event.days().iter().map(|d| CalendarDay::new(*d)).collect();
+    award_last_stars(&mut days);
+    days
+}
+
+/// Prints the given days of the calendar, preceded by the progress of the whole calendar.
+pub fn print_calendar(year: u16, calendar: &[CalendarDay], days: &[u8], tag: Option<&str>) {
+    println!(
+        "{} {}: {} days completed, {} stars",
+        "Advent of Code".green().bold(),
+        year,
+        calendar.iter().filter(|d| d.stars == 2).count(),
+        calendar.iter().map(|d| d.stars as u32).sum::<u32>()
+    );
+
+    let shown: Vec<&CalendarDay> = calendar
+        .iter()
+        .filter(|d| days.contains(&d.day))
+        .filter(|d| tag.is_none_or(|tag| d.tags.contains(&tag)))
+        .collect();
+    let width = shown
+        .iter()
+        .map(|d| d.title.map_or(1, str::len))
+        .max()
+        .unwrap_or(0);
+
+    for day in shown {
+        let stars = format!(
+            "{}{}",
+            "*".repeat(day.stars as usize),
+            ".".repeat(2 - day.stars as usize)
+        );
+        let notes: Vec<String> = day
+            .parts
+            .iter()
+            .enumerate()
+            .filter(|(_, status)| **status == PartStatus::InProgress)
+            .map(|(i, status)| format!("part {} {}", i + 1, status))
+            .collect();
+
+        let line = format!(
+            "{:>3}  {}  {:<width$}  {}{}",
+            day.day,
+            stars.yellow().bold(),
+            day.title.unwrap_or("-"),
+            day.tags.join(", ").dimmed(),
+            if notes.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", notes.join(", ")).italic().to_string()
+            },
+            width = width
+        );
+        println!("{}", line.trim_end());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, DayImpl, Registered, SolveError, TestCase};
+
+    struct Solved;
+    struct Unfinished;
+    struct Stub;
+
+    macro_rules! day_impl {
+        ($day:ty { $($item:item)* }) => {
+            impl DayImpl<()> for $day {
+                $($item)*
+
+                fn test_cases() -> Vec<TestCase> {
+                    vec![]
+                }
+
+                fn init(_input: &str) -> Result<(Self, ()), SolveError> {
+                    Ok((Self, ()))
+                }
+
+                fn one(&self, _data: &mut ()) -> Result<Answer, SolveError> {
+                    Ok(Answer::Number(0))
+                }
+
+                fn two(&self, _data: &mut ()) -> Result<Answer, SolveError> {
+                    Ok(Answer::Number(0))
+                }
+            }
+        };
+    }
+
+    day_impl!(Solved {
+        const TITLE: Option<&'static str> = Some("Trebuchet?!");
+        const TAGS: &'static [&'static str] = &["strings", "parsing"];
+    });
+    day_impl!(Unfinished {
+        const TAGS: &'static [&'static str] = &["grid", "pathfinding"];
+        const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::InProgress];
+    });
+    day_impl!(Stub {
+        const IMPLEMENTED: bool = false;
+    });
+
+    static DAYS: [&dyn DynDay; 3] = [
+        &Registered::<Solved, _>::new(1),
+        &Registered::<Unfinished, _>::new(18),
+        &Registered::<Stub, _>::new(25),
+    ];
+
+    fn day(day: u8, parts: Vec<PartStatus>) -> CalendarDay {
+        CalendarDay {
+            day,
+            title: None,
+            tags: &[],
+            stars: parts.iter().filter(|p| **p == PartStatus::Solved).count() as u8,
+            parts,
+        }
+    }
+
+    #[test]
+    fn reads_the_metadata_of_the_days() {
+        let calendar = calendar(&Event::new(2023, &DAYS));
+
+        assert_eq!(calendar.len(), 3);
+        assert_eq!(calendar[0].title, Some("Trebuchet?!"));
+        assert_eq!(calendar[0].stars, 2);
+        assert_eq!(
+            calendar[1].parts,
+            vec![PartStatus::Solved, PartStatus::InProgress]
+        );
+        assert_eq!(calendar[1].stars, 1);
+        assert!(calendar[1].tags.contains(&"pathfinding"));
+        assert_eq!(calendar[2].parts, vec![PartStatus::NotStarted]);
+        assert_eq!(calendar[2].stars, 0);
+    }
+
+    #[test]
+    fn awards_the_last_star_once_everything_is_solved() {
+        let mut days = vec![
+            day(24, vec![PartStatus::Solved, PartStatus::InProgress]),
+            day(25, vec![PartStatus::Solved]),
+        ];
+        award_last_stars(&mut days);
+        assert_eq!(days[1].stars, 1);
+
+        days[0] = day(24, vec![PartStatus::Solved, PartStatus::Solved]);
+        award_last_stars(&mut days);
+        assert_eq!(days[1].stars, 2);
+    }
+}
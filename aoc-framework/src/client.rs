@@ -0,0 +1,627 @@
+use crate::dynamic_range_time_format;
+use crate::{validate_input, InvalidInput};
+use reqwest::blocking::Client;
+use reqwest::cookie::Jar;
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Where the puzzles are hosted, unless another base URL is configured.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The event whose inputs are downloaded, unless another year is configured.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Identifies this tool to the server, unless another user agent is configured.
+pub const DEFAULT_USER_AGENT: &str = "https://github.com/LeMoonStar/AoC23 aoc23@unitcore.de";
+
+/// Which server and event the client talks to, how it identifies itself,
+/// and how often failed downloads are retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientConfig {
+    /// Plain HTTP is only allowed if the base URL uses it.
+    pub base_url: Url,
+    pub year: u16,
+    pub user_agent: String,
+    /// How often a download is attempted before giving up on transient errors.
+    pub attempts: u32,
+    /// How long to wait before the first retry. Doubles with every retry.
+    pub retry_delay: Duration,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            base_url: Url::parse(DEFAULT_BASE_URL).unwrap(),
+            year: DEFAULT_YEAR,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            attempts: 3,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Why an input couldn't be downloaded.
+#[derive(Debug)]
+pub enum DownloadError {
+    /// Neither a session nor a cached input is available.
+    MissingSession,
+    /// The server asked to log in, so the session is invalid or expired.
+    InvalidSession,
+    /// The puzzle doesn't exist, most likely because it isn't unlocked yet.
+    NotUnlocked,
+    /// Too many requests were sent.
+    RateLimited,
+    /// Any other unsuccessful response.
+    Server(StatusCode),
+    /// The response was successful, but doesn't look like an input.
+    InvalidInput(InvalidInput),
+    /// The server couldn't be reached, or the connection failed.
+    Network(reqwest::Error),
+}
+
+impl DownloadError {
+    /// Whether trying again later could succeed.
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::RateLimited | Self::Network(_) => true,
+            Self::Server(status) => status.is_server_error(),
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::MissingSession => write!(
+                f,
+                "no session was provided using the session argument, the AOC_SESSION environment variable or the login command, and there is no cache of the day's input"
+            ),
+            Self::InvalidSession => write!(
+                f,
+                "the session is invalid or has expired, log in again and copy the new session cookie"
+            ),
+            Self::NotUnlocked => write!(f, "the puzzle isn't unlocked yet"),
+            Self::RateLimited => write!(f, "too many requests, try again later"),
+            Self::Server(status) => write!(f, "the server responded with {}", status),
+            Self::InvalidInput(reason) => write!(f, "the server returned no valid input, {}", reason),
+            Self::Network(err) => write!(f, "couldn't reach the server: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for DownloadError {}
+
+/// The server's reaction to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. Contains how long to wait, if the page says so.
+    RateLimited(Option<Duration>),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    /// The response didn't contain any of the known messages.
+    /// Contains the text of the response's main article.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Parses the HTML page returned after submitting an answer.
+    pub fn parse(html: &str) -> Self {
+        let text = strip_tags(match (html.find("<article"), html.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &html[start..end],
+            _ => html,
+        });
+
+        if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Self::TooHigh
+            } else if text.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Incorrect
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Self::RateLimited(parse_wait_time(&text))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown(text.trim().to_owned())
+        }
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!"),
+            Self::Incorrect => write!(f, "That's not the right answer."),
+            Self::TooHigh => write!(f, "That's not the right answer, it's too high."),
+            Self::TooLow => write!(f, "That's not the right answer, it's too low."),
+            Self::RateLimited(Some(wait)) => write!(
+                f,
+                "An answer was submitted too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Self::RateLimited(None) => write!(
+                f,
+                "An answer was submitted too recently, wait before trying again."
+            ),
+            Self::WrongLevel => write!(
+                f,
+                "This part can't be submitted, it's either already solved or still locked."
+            ),
+            Self::Unknown(text) => write!(f, "Unexpected response: {}", text),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/// Reads the wait time from messages like "You have 1m 5s left to wait."
+fn parse_wait_time(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for part in text[start..end].split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Reads the name of the logged in user from the header of a page,
+/// e.g. `<div class="user">LeMoonStar <span class="star-count">50*</span></div>`.
+/// Returns `None` if nobody is logged in.
+fn parse_user(html: &str) -> Option<String> {
+    let start = html.find("<div class=\"user\">")? + "<div class=\"user\">".len();
+    let end = start + html[start..].find('<').unwrap_or(html.len() - start);
+
+    let user = html[start..end].trim();
+    (!user.is_empty()).then(|| user.replace("&amp;", "&"))
+}
+
+/// An HTTP client for Advent of Code, logged in using a session token.
+pub struct AocClient {
+    config: ClientConfig,
+    client: Client,
+}
+
+impl AocClient {
+    pub fn new(config: ClientConfig, session: &str) -> reqwest::Result<Self> {
+        let cookie_jar = Jar::default();
+        cookie_jar.add_cookie_str(&format!("session={}", session), &config.base_url);
+        let client = Client::builder()
+            .https_only(config.base_url.scheme() == "https")
+            .cookie_provider(Arc::new(cookie_jar))
+            .user_agent(config.user_agent.as_str())
+            .build()?;
+
+        Ok(Self { config, client })
+    }
+
+    /// Resolves a path relative to the base URL, keeping any path prefix of it.
+    fn endpoint(&self, path: &str) -> Url {
+        let mut base = self.config.base_url.clone();
+        if !base.path().ends_with('/') {
+            base.set_path(&format!("{}/", base.path()));
+        }
+        base.join(path).expect("Failed to build the request URL.")
+    }
+
+    /// The URL of a page of a day, e.g. `input`, or of the puzzle itself if `page` is empty.
+    fn url(&self, day: u8, page: &str) -> Url {
+        let mut path = format!("{}/day/{}", self.config.year, day);
+        if !page.is_empty() {
+            path = format!("{}/{}", path, page);
+        }
+        self.endpoint(&path)
+    }
+
+    /// Checks the session by looking up the name of the user it belongs to on the event's page.
+    pub fn user(&self) -> Result<String, DownloadError> {
+        let url = self.endpoint(&self.config.year.to_string());
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .map_err(DownloadError::Network)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(match status {
+                StatusCode::TOO_MANY_REQUESTS => DownloadError::RateLimited,
+                StatusCode::BAD_REQUEST => DownloadError::InvalidSession,
+                _ => DownloadError::Server(status),
+            });
+        }
+        parse_user(&response.text().map_err(DownloadError::Network)?)
+            .ok_or(DownloadError::InvalidSession)
+    }
+
+    /// Downloads the puzzle input of a day.
+    /// Transient errors are retried with an exponential backoff.
+    pub fn download_input(&self, day: u8) -> Result<String, DownloadError> {
+        self.with_retries("input", || {
+            let input = self.get(self.url(day, "input"))?;
+            match validate_input(&input) {
+                Ok(()) => Ok(input),
+                Err(InvalidInput::RequestedEarly) => Err(DownloadError::NotUnlocked),
+                Err(reason) => Err(DownloadError::InvalidInput(reason)),
+            }
+        })
+    }
+
+    /// Downloads the HTML page describing the puzzle of a day. The second part
+    /// is only included once the first one has been solved using this session.
+    /// Transient errors are retried with an exponential backoff.
+    pub fn download_puzzle(&self, day: u8) -> Result<String, DownloadError> {
+        self.with_retries("puzzle", || self.get(self.url(day, "")))
+    }
+
+    /// Retries a download while it fails with a transient error, waiting twice as long each time.
+    fn with_retries<T, F>(&self, what: &str, download: F) -> Result<T, DownloadError>
+    where
+        F: Fn() -> Result<T, DownloadError>,
+    {
+        let mut delay = self.config.retry_delay;
+        let mut attempt = 1;
+
+        loop {
+            match download() {
+                Err(err) if err.is_transient() && attempt < self.config.attempts => {
+                    eprintln!(
+                        "Downloading {} failed: {}, retrying in {}",
+                        what,
+                        err,
+                        dynamic_range_time_format(&delay)
+                    );
+                    thread::sleep(delay);
+                    delay *= 2;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Requests a page of the day, classifying unsuccessful responses.
+    fn get(&self, url: Url) -> Result<String, DownloadError> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .map_err(DownloadError::Network)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return response.text().map_err(DownloadError::Network);
+        }
+
+        let body = response.text().unwrap_or_default();
+        Err(match status {
+            StatusCode::NOT_FOUND => DownloadError::NotUnlocked,
+            StatusCode::TOO_MANY_REQUESTS => DownloadError::RateLimited,
+            StatusCode::BAD_REQUEST => DownloadError::InvalidSession,
+            _ if body.contains("log in") => DownloadError::InvalidSession,
+            _ => DownloadError::Server(status),
+        })
+    }
+
+    /// Submits the answer for a part of a day and returns the server's verdict.
+    pub fn submit_answer(&self, day: u8, part: u8, answer: &str) -> reqwest::Result<Verdict> {
+        let response = self
+            .client
+            .post(self.url(day, "answer"))
+            .form(&[("level", part.to_string()), ("answer", answer.to_owned())])
+            .send()?
+            .error_for_status()?;
+
+        Ok(Verdict::parse(&response.text()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread::JoinHandle;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let cases = [
+            ("That's the right answer! You are <em>one gold star</em> closer.", Verdict::Correct),
+            ("That's not the right answer. If you're stuck, ...", Verdict::Incorrect),
+            ("That's not the right answer; your answer is too high.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+                Verdict::RateLimited(Some(Duration::from_secs(65))),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::WrongLevel,
+            ),
+            ("Something else", Verdict::Unknown("Something else".to_owned())),
+        ];
+
+        for (message, verdict) in cases {
+            assert_eq!(Verdict::parse(&page(message)), verdict, "{}", message);
+        }
+    }
+
+    fn read_request(stream: &mut TcpStream) -> String {
+        let mut request = String::new();
+        let mut buffer = [0; 4096];
+
+        loop {
+            if let Some(end) = request.find("\r\n\r\n") {
+                let length = request[..end]
+                    .lines()
+                    .find_map(|v| {
+                        v.to_lowercase()
+                            .strip_prefix("content-length: ")
+                            .map(|v| v.trim().parse::<usize>().unwrap())
+                    })
+                    .unwrap_or(0);
+                if request.len() >= end + 4 + length {
+                    return request;
+                }
+            }
+
+            let len = stream.read(&mut buffer).unwrap();
+            if len == 0 {
+                return request;
+            }
+            request += &String::from_utf8_lossy(&buffer[..len]);
+        }
+    }
+
+    /// Serves the responses to one request each, and returns the received requests.
+    fn serve(responses: Vec<(u16, String)>) -> (ClientConfig, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let config = ClientConfig {
+            base_url: Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap(),
+            retry_delay: Duration::ZERO,
+            ..ClientConfig::default()
+        };
+
+        let server = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                requests.push(read_request(&mut stream));
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (config, server)
+    }
+
+    #[test]
+    fn looks_up_the_user() {
+        let header = |user: &str| {
+            format!(
+                "<html><body><header><div><h1 class=\"title-global\"><a href=\"/\">Advent of Code</a></h1>{}</div></header></body></html>",
+                user
+            )
+        };
+        let (config, server) = serve(vec![
+            (
+                200,
+                header("<div class=\"user\">LeMoonStar <a href=\"/2023/support\" class=\"supporter-badge\">AoC++</a> <span class=\"star-count\">36*</span></div>"),
+            ),
+            (
+                200,
+                header("<div class=\"user\">(anonymous user #1234567)</div>"),
+            ),
+            (
+                200,
+                header("<nav><ul><li><a href=\"/2023/auth/login\">[Log In]</a></li></ul></nav>"),
+            ),
+        ]);
+        let client = AocClient::new(config, "secret").unwrap();
+
+        assert_eq!(client.user().unwrap(), "LeMoonStar");
+        assert_eq!(client.user().unwrap(), "(anonymous user #1234567)");
+        assert!(matches!(client.user(), Err(DownloadError::InvalidSession)));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2023 "), "{}", requests[0]);
+        assert!(requests[0].contains("session=secret"), "{}", requests[0]);
+    }
+
+    #[test]
+    fn downloads_puzzles() {
+        let (config, server) = serve(vec![(200, page("--- Day 7: Camel Cards ---"))]);
+
+        let client = AocClient::new(config, "secret").unwrap();
+        assert!(client.download_puzzle(7).unwrap().contains("Camel Cards"));
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("GET /2023/day/7 "), "{}", request);
+    }
+
+    #[test]
+    fn submits_to_base_url() {
+        let (config, server) = serve(vec![(
+            200,
+            page("That's not the right answer; your answer is too low."),
+        )]);
+        let config = ClientConfig {
+            year: 2022,
+            user_agent: "tester".to_owned(),
+            ..config
+        };
+
+        let client = AocClient::new(config, "secret").unwrap();
+        assert_eq!(client.submit_answer(5, 2, "42").unwrap(), Verdict::TooLow);
+
+        let request = &server.join().unwrap()[0];
+        assert!(
+            request.starts_with("POST /2022/day/5/answer "),
+            "{}",
+            request
+        );
+        assert!(
+            request.to_lowercase().contains("user-agent: tester"),
+            "{}",
+            request
+        );
+        assert!(request.contains("session=secret"), "{}", request);
+        assert!(request.ends_with("level=2&answer=42"), "{}", request);
+    }
+
+    #[test]
+    fn keeps_the_base_url_prefix() {
+        let (config, server) = serve(vec![
+            (200, "1\n2\n".to_owned()),
+            (200, page("--- Day 7: Camel Cards ---")),
+            (200, page("That's the right answer!")),
+        ]);
+        let mut base_url = config.base_url.clone();
+        base_url.set_path("/mirror/aoc");
+        let client = AocClient::new(ClientConfig { base_url, ..config }, "secret").unwrap();
+
+        assert_eq!(client.download_input(3).unwrap(), "1\n2\n");
+        assert!(client.download_puzzle(7).unwrap().contains("Camel Cards"));
+        assert_eq!(client.submit_answer(5, 1, "42").unwrap(), Verdict::Correct);
+
+        let requests = server.join().unwrap();
+        assert!(
+            requests[0].starts_with("GET /mirror/aoc/2023/day/3/input "),
+            "{}",
+            requests[0]
+        );
+        assert!(
+            requests[1].starts_with("GET /mirror/aoc/2023/day/7 "),
+            "{}",
+            requests[1]
+        );
+        assert!(
+            requests[2].starts_with("POST /mirror/aoc/2023/day/5/answer "),
+            "{}",
+            requests[2]
+        );
+    }
+
+    #[test]
+    fn retries_transient_download_errors() {
+        let (config, server) = serve(vec![
+            (500, "Internal Server Error".to_owned()),
+            (429, String::new()),
+            (200, "1\n2\n".to_owned()),
+        ]);
+
+        let client = AocClient::new(config, "secret").unwrap();
+        assert_eq!(client.download_input(3).unwrap(), "1\n2\n");
+        assert_eq!(server.join().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn classifies_download_errors() {
+        let (config, server) = serve(vec![
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!".to_owned(),
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_owned(),
+            ),
+            (500, "Please log in.".to_owned()),
+            (503, String::new()),
+            (
+                200,
+                "Please don't repeatedly request this endpoint before it unlocks!".to_owned(),
+            ),
+            (200, page("Internal Server Error")),
+            (200, String::new()),
+        ]);
+        let client = AocClient::new(
+            ClientConfig {
+                attempts: 1,
+                ..config
+            },
+            "secret",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            client.download_input(1),
+            Err(DownloadError::NotUnlocked)
+        ));
+        assert!(matches!(
+            client.download_input(1),
+            Err(DownloadError::InvalidSession)
+        ));
+        assert!(matches!(
+            client.download_input(1),
+            Err(DownloadError::InvalidSession)
+        ));
+        assert!(matches!(
+            client.download_input(1),
+            Err(DownloadError::Server(StatusCode::SERVICE_UNAVAILABLE))
+        ));
+        assert!(matches!(
+            client.download_input(1),
+            Err(DownloadError::NotUnlocked)
+        ));
+        assert!(matches!(
+            client.download_input(1),
+            Err(DownloadError::InvalidInput(InvalidInput::Html))
+        ));
+        assert!(matches!(
+            client.download_input(1),
+            Err(DownloadError::InvalidInput(InvalidInput::Empty))
+        ));
+        assert_eq!(server.join().unwrap().len(), 7);
+    }
+}
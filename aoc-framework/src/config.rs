@@ -0,0 +1,505 @@
+use crate::cache::{default_cache_dir, CACHE_DIR_VAR};
+use crate::client::{ClientConfig, DEFAULT_BASE_URL, DEFAULT_USER_AGENT, DEFAULT_YEAR};
+use crate::output::OutputFormat;
+use crate::session::{default_session_path, SESSION_FILE_VAR};
+use crate::{Part, Verbosity};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The project configuration file, read from the working directory.
+pub const CONFIG_FILE: &str = "aoc23.toml";
+
+/// The environment variable which overrides where the user configuration file is read from.
+pub const USER_CONFIG_VAR: &str = "AOC_CONFIG_FILE";
+
+/// Settings read from a configuration file. Unset settings are `None`,
+/// and are taken from the environment, other files or the defaults instead.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    /// The server inputs are downloaded from and answers are submitted to.
+    pub base_url: Option<String>,
+    /// The event whose inputs are downloaded.
+    pub year: Option<u16>,
+    /// The user agent sent with every request.
+    pub user_agent: Option<String>,
+    /// Where inputs, answers and the submission history are stored.
+    pub cache_dir: Option<PathBuf>,
+    /// The template the new-day command generates days from.
+    pub day_template: Option<PathBuf>,
+    /// Where the session is read from and stored by the login command.
+    pub session_file: Option<PathBuf>,
+    /// The parts of a day to compute.
+    pub part: Option<Part>,
+    /// The format results are printed in.
+    pub output: Option<OutputFormat>,
+    /// Whether the output is colored.
+    #[serde(alias = "colour")]
+    pub color: Option<Color>,
+    /// How much the solutions print while running.
+    pub verbosity: Option<Verbosity>,
+    /// The maximum amount of measured runs of the bench command.
+    pub bench_iterations: Option<usize>,
+}
+
+/// Loads a configuration file. A missing file results in an empty configuration.
+/// Relative paths in the file are relative to the directory it's in.
+pub fn load_config_file(path: &Path) -> io::Result<ConfigFile> {
+    let mut file: ConfigFile = match fs::read_to_string(path) {
+        Ok(content) => toml::from_str(&content).map_err(io::Error::other)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(ConfigFile::default()),
+        Err(err) => return Err(err),
+    };
+
+    let dir = path.parent().unwrap_or(Path::new(""));
+    for path in [
+        &mut file.cache_dir,
+        &mut file.day_template,
+        &mut file.session_file,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *path = dir.join(&path);
+    }
+    Ok(file)
+}
+
+/// The user's configuration directory: `aoc23` in `$XDG_CONFIG_HOME`
+/// (`~/.config` if unset, `%APPDATA%` on Windows).
+pub(crate) fn user_config_dir() -> Option<PathBuf> {
+    let non_empty = |var: &str| {
+        env::var_os(var)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = if cfg!(windows) {
+        non_empty("APPDATA")
+    } else {
+        non_empty("XDG_CONFIG_HOME").or_else(|| non_empty("HOME").map(|home| home.join(".config")))
+    };
+    base.map(|base| base.join("aoc23"))
+}
+
+/// The user configuration file, `aoc23/aoc23.toml` in the user's configuration
+/// directory unless `AOC_CONFIG_FILE` is set.
+pub fn user_config_path() -> Option<PathBuf> {
+    env::var_os(USER_CONFIG_VAR)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| user_config_dir().map(|dir| dir.join(CONFIG_FILE)))
+}
+
+/// The loaded configuration files, see [`load_config_files`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigFiles {
+    /// The files in order of precedence, along with their paths.
+    pub files: Vec<(PathBuf, ConfigFile)>,
+    /// Settings which were ignored.
+    pub warnings: Vec<String>,
+}
+
+/// Loads the configuration files in order of precedence: `aoc23.toml` in the
+/// current directory, then the user configuration file. Returns the path of
+/// the file which couldn't be read on failure.
+pub fn load_config_files() -> Result<ConfigFiles, (PathBuf, io::Error)> {
+    load_files(PathBuf::from(CONFIG_FILE), user_config_path())
+}
+
+fn load_files(
+    project: PathBuf,
+    user: Option<PathBuf>,
+) -> Result<ConfigFiles, (PathBuf, io::Error)> {
+    let load = |path: PathBuf| match load_config_file(&path) {
+        Ok(file) => Ok((path, file)),
+        Err(err) => Err((path, err)),
+    };
+
+    // The session is sent to the base URL, so a cloned repository mustn't be able to
+    // redirect it. The user agent is kept together with the server it's sent to.
+    let (project, mut file) = load(project)?;
+    let mut warnings = vec![];
+    for (name, setting) in [
+        ("base_url", &mut file.base_url),
+        ("user_agent", &mut file.user_agent),
+    ] {
+        if setting.take().is_some() {
+            warnings.push(format!(
+                "{} in {} is ignored, as the session is sent to the server. Set it in the user configuration, the environment or on the command line instead.",
+                name,
+                project.display()
+            ));
+        }
+    }
+
+    let mut files = vec![(project, file)];
+    if let Some(user) = user {
+        files.push(load(user)?);
+    }
+    Ok(ConfigFiles { files, warnings })
+}
+
+/// Whether the output is colored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Color {
+    /// Color the output if it's printed to a terminal and NO_COLOR isn't set.
+    Auto,
+    /// Always color the output.
+    Always,
+    /// Never color the output.
+    Never,
+}
+
+/// Where the value of a setting came from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Default,
+    /// A configuration file.
+    File(PathBuf),
+    /// An environment variable.
+    Environment(&'static str),
+    CommandLine,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Environment(var) => write!(f, "${}", var),
+            Self::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+/// The value of a setting, and where it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Setting<T> {
+    pub fn new(value: T, source: Source) -> Self {
+        Self { value, source }
+    }
+
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> Setting<U> {
+        Setting::new(f(self.value), self.source)
+    }
+}
+
+/// The effective configuration, merged from the command line, the environment,
+/// the configuration files and the defaults, in that order of precedence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub base_url: Setting<Url>,
+    pub year: Setting<u16>,
+    pub user_agent: Setting<String>,
+    pub cache_dir: Setting<PathBuf>,
+    pub day_template: Setting<Option<PathBuf>>,
+    pub session_file: Setting<Option<PathBuf>>,
+    pub part: Setting<Part>,
+    pub output: Setting<OutputFormat>,
+    pub color: Setting<Color>,
+    pub verbosity: Setting<Verbosity>,
+    pub bench_iterations: Setting<Option<usize>>,
+}
+
+/// The setting, or its default if it isn't set.
+fn or_default<T>(setting: Option<Setting<T>>, default: T) -> Setting<T> {
+    setting.unwrap_or_else(|| Setting::new(default, Source::Default))
+}
+
+/// The setting, or `None` if it isn't set.
+fn optional<T>(setting: Option<Setting<T>>) -> Setting<Option<T>> {
+    match setting {
+        Some(setting) => setting.map(Some),
+        None => Setting::new(None, Source::Default),
+    }
+}
+
+/// Looks up settings in each of the sources.
+struct Sources<'a, E> {
+    args: &'a ConfigFile,
+    env: E,
+    files: &'a [(PathBuf, ConfigFile)],
+}
+
+impl<E: Fn(&str) -> Option<String>> Sources<'_, E> {
+    /// The setting given on the command line, or in the first file which sets it.
+    fn get<T>(&self, get: impl Fn(&ConfigFile) -> Option<T>) -> Option<Setting<T>> {
+        if let Some(value) = get(self.args) {
+            return Some(Setting::new(value, Source::CommandLine));
+        }
+        self.files.iter().find_map(|(path, file)| {
+            get(file).map(|value| Setting::new(value, Source::File(path.clone())))
+        })
+    }
+
+    /// Like [`Self::get`], but the environment variable takes precedence over the files.
+    fn get_or_env<T>(
+        &self,
+        var: &'static str,
+        get: impl Fn(&ConfigFile) -> Option<T>,
+    ) -> Result<Option<Setting<T>>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if let Some(value) = get(self.args) {
+            return Ok(Some(Setting::new(value, Source::CommandLine)));
+        }
+        if let Some(value) = (self.env)(var).filter(|v| !v.is_empty()) {
+            return match value.parse() {
+                Ok(parsed) => Ok(Some(Setting::new(parsed, Source::Environment(var)))),
+                Err(err) => Err(format!("Invalid value '{}' for {}: {}", value, var, err)),
+            };
+        }
+        Ok(self.get(get))
+    }
+}
+
+impl Config {
+    /// Merges the settings given on the command line, the environment variables read
+    /// using `env`, and the configuration files, of which earlier ones take precedence.
+    pub fn resolve(
+        args: &ConfigFile,
+        env: impl Fn(&str) -> Option<String>,
+        files: &[(PathBuf, ConfigFile)],
+    ) -> Result<Self, String> {
+        let sources = Sources { args, env, files };
+        let base_url = or_default(
+            sources.get_or_env("AOC_BASE_URL", |v| v.base_url.clone())?,
+            DEFAULT_BASE_URL.to_owned(),
+        );
+        let base_url = Setting::new(
+            base_url.value.parse().map_err(|err| {
+                format!(
+                    "Invalid base URL '{}' ({}): {}",
+                    base_url.value, base_url.source, err
+                )
+            })?,
+            base_url.source,
+        );
+
+        Ok(Self {
+            base_url,
+            year: or_default(sources.get_or_env("AOC_YEAR", |v| v.year)?, DEFAULT_YEAR),
+            user_agent: or_default(
+                sources.get_or_env("AOC_USER_AGENT", |v| v.user_agent.clone())?,
+                DEFAULT_USER_AGENT.to_owned(),
+            ),
+            cache_dir: sources
+                .get_or_env(CACHE_DIR_VAR, |v| v.cache_dir.clone())?
+                .unwrap_or_else(|| Setting::new(default_cache_dir(), Source::Default)),
+            day_template: optional(sources.get(|v| v.day_template.clone())),
+            session_file: match sources.get_or_env(SESSION_FILE_VAR, |v| v.session_file.clone())? {
+                Some(setting) => setting.map(Some),
+                None => Setting::new(default_session_path(), Source::Default),
+            },
+            part: or_default(sources.get(|v| v.part.clone()), Part::Both),
+            output: or_default(sources.get(|v| v.output.clone()), OutputFormat::Text),
+            color: or_default(sources.get(|v| v.color), Color::Auto),
+            verbosity: or_default(sources.get(|v| v.verbosity.clone()), Verbosity::None),
+            bench_iterations: optional(sources.get(|v| v.bench_iterations)),
+        })
+    }
+
+    /// The settings of the client downloading inputs and submitting answers.
+    pub fn client_config(&self) -> ClientConfig {
+        ClientConfig {
+            base_url: self.base_url.value.clone(),
+            year: self.year.value,
+            user_agent: self.user_agent.value.clone(),
+            ..ClientConfig::default()
+        }
+    }
+
+    /// Formats the configuration like a configuration file, with where each setting
+    /// came from as a comment. Settings which aren't set are commented out.
+    pub fn show(&self) -> String {
+        fn line<T: Serialize>(name: &str, setting: &Setting<T>) -> (String, String) {
+            let value = match toml::Value::try_from(&setting.value) {
+                Ok(value) => format!("{} = {}", name, value),
+                Err(_) => format!("# {} =", name),
+            };
+            (value, setting.source.to_string())
+        }
+
+        let lines = [
+            line("base_url", &self.base_url.clone().map(String::from)),
+            line("year", &self.year),
+            line("user_agent", &self.user_agent),
+            line("cache_dir", &self.cache_dir),
+            line("day_template", &self.day_template),
+            line("session_file", &self.session_file),
+            line("part", &self.part),
+            line("output", &self.output),
+            line("color", &self.color),
+            line("verbosity", &self.verbosity),
+            line("bench_iterations", &self.bench_iterations),
+        ];
+        let width = lines
+            .iter()
+            .map(|(value, _)| value.len())
+            .max()
+            .unwrap_or(0);
+        lines
+            .iter()
+            .map(|(value, source)| format!("{:width$}  # {}", value, source, width = width))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::temp_dir;
+
+    fn file(content: &str) -> ConfigFile {
+        toml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn merges_sources() {
+        let args = ConfigFile {
+            part: Some(Part::One),
+            ..ConfigFile::default()
+        };
+        let env = |var: &str| match var {
+            "AOC_YEAR" => Some("2022".to_owned()),
+            "AOC_USER_AGENT" => Some(String::new()),
+            _ => None,
+        };
+        let files = [
+            (
+                PathBuf::from("aoc23.toml"),
+                file("year = 2021\noutput = \"json\"\npart = \"2\""),
+            ),
+            (
+                PathBuf::from("user.toml"),
+                file("output = \"csv\"\ncolour = \"never\"\nverbosity = \"dev\"\nbench_iterations = 50"),
+            ),
+        ];
+        let config = Config::resolve(&args, env, &files).unwrap();
+
+        assert_eq!(config.part, Setting::new(Part::One, Source::CommandLine));
+        assert_eq!(
+            config.year,
+            Setting::new(2022, Source::Environment("AOC_YEAR"))
+        );
+        assert_eq!(
+            config.output,
+            Setting::new(OutputFormat::Json, Source::File("aoc23.toml".into()))
+        );
+        assert_eq!(
+            config.color,
+            Setting::new(Color::Never, Source::File("user.toml".into()))
+        );
+        assert_eq!(config.verbosity.value, Verbosity::Development);
+        assert_eq!(config.bench_iterations.value, Some(50));
+        // Empty environment variables are ignored.
+        assert_eq!(
+            config.user_agent,
+            Setting::new(DEFAULT_USER_AGENT.to_owned(), Source::Default)
+        );
+        assert_eq!(config.day_template, Setting::new(None, Source::Default));
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        let env = |var: &str| (var == "AOC_YEAR").then(|| "twenty".to_owned());
+        assert!(Config::resolve(&ConfigFile::default(), env, &[]).is_err());
+
+        let files = [(PathBuf::from("aoc23.toml"), file("base_url = \"nope\""))];
+        let err = Config::resolve(&ConfigFile::default(), |_| None, &files).unwrap_err();
+        assert!(err.contains("aoc23.toml"), "{}", err);
+
+        assert!(toml::from_str::<ConfigFile>("yaer = 2022").is_err());
+        assert!(toml::from_str::<ConfigFile>("part = \"3\"").is_err());
+    }
+
+    #[test]
+    fn shows_sources() {
+        let files = [(
+            PathBuf::from("aoc23.toml"),
+            file("year = 2022\npart = \"1\""),
+        )];
+        let config = Config::resolve(&ConfigFile::default(), |_| None, &files).unwrap();
+        let shown = config.show();
+
+        assert!(shown.contains("year = 2022"), "{}", shown);
+        assert!(shown.contains("part = \"1\""), "{}", shown);
+        assert!(shown
+            .lines()
+            .any(|v| v.starts_with("year = 2022") && v.ends_with("# aoc23.toml")));
+        assert!(shown
+            .lines()
+            .any(|v| v.starts_with("# day_template =") && v.ends_with("# default")));
+        // The shown configuration can be read again.
+        let shown: ConfigFile = toml::from_str(&shown).unwrap();
+        assert_eq!(shown.year, Some(2022));
+        assert_eq!(shown.output, Some(OutputFormat::Text));
+    }
+
+    #[test]
+    fn ignores_the_server_in_project_files() {
+        let dir = temp_dir("config-project");
+        fs::create_dir_all(&dir).unwrap();
+        let settings = "base_url = \"https://example.com/\"\nuser_agent = \"x\"\nyear = 2022";
+        fs::write(dir.join("project.toml"), settings).unwrap();
+        fs::write(dir.join("user.toml"), settings).unwrap();
+
+        let ConfigFiles { files, warnings } =
+            load_files(dir.join("project.toml"), Some(dir.join("user.toml"))).unwrap();
+        assert_eq!(files[0].1.base_url, None);
+        assert_eq!(files[0].1.user_agent, None);
+        assert_eq!(files[0].1.year, Some(2022));
+        assert_eq!(files[1].1.base_url, Some("https://example.com/".to_owned()));
+        assert_eq!(files[1].1.user_agent, Some("x".to_owned()));
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("base_url in "), "{}", warnings[0]);
+
+        let ConfigFiles { files, warnings } = load_files(dir.join("missing.toml"), None).unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(warnings.is_empty());
+
+        fs::write(dir.join("user.toml"), "unknown = 1").unwrap();
+        let (path, _) =
+            load_files(dir.join("project.toml"), Some(dir.join("user.toml"))).unwrap_err();
+        assert_eq!(path, dir.join("user.toml"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn resolves_paths_relative_to_file() {
+        let dir = temp_dir("config");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(CONFIG_FILE);
+        fs::write(
+            &path,
+            "cache_dir = \"cache\"\nsession_file = \"/tmp/session\"",
+        )
+        .unwrap();
+
+        let file = load_config_file(&path).unwrap();
+        assert_eq!(file.cache_dir, Some(dir.join("cache")));
+        assert_eq!(file.session_file, Some(PathBuf::from("/tmp/session")));
+        assert_eq!(
+            load_config_file(&dir.join("missing.toml")).unwrap(),
+            ConfigFile::default()
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
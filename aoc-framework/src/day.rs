@@ -0,0 +1,712 @@
+use crate::Part;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+// Thanks to andi-makes with his AoC project https://github.com/andi-makes/aoc2021,
+// this system is heavily inspired by his system.
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Number(u64),
+    String(String),
+    Bitmap(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    fn append_per_line(str: String, prefix: &str) -> String {
+        str.lines()
+            .map(|v| prefix.to_owned() + v + "\n")
+            .collect::<String>()
+            .strip_suffix('\n')
+            .unwrap()
+            .to_owned()
+    }
+
+    /// Renders each row of a bitmap as a string, using `#` for set and `.` for unset pixels.
+    pub fn bitmap_rows(bm: &[Vec<bool>]) -> Vec<String> {
+        bm.iter()
+            .map(|row| row.iter().map(|v| if *v { '#' } else { '.' }).collect())
+            .collect()
+    }
+
+    /// The text to submit for this answer.
+    /// Bitmaps have to be read by a human first, so they have none.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Self::Number(n) => Some(n.to_string()),
+            Self::String(s) => Some(s.clone()),
+            Self::Bitmap(_) => None,
+        }
+    }
+
+    fn bm_get(bm: &[Vec<bool>], x: usize, y: usize) -> bool {
+        if let Some(line) = bm.get(y) {
+            if let Some(v) = line.get(x) {
+                return *v;
+            }
+            return false;
+        }
+        false
+    }
+
+    fn minify_bitmap(bm: &[Vec<bool>]) -> String {
+        let height = bm.len();
+        let width = bm[0].len();
+
+        let mut out = String::new();
+
+        for y in (0..height).step_by(2) {
+            if y != 0 {
+                out += "\n"
+            }
+            for x in (0..width).step_by(2) {
+                let m = (
+                    Self::bm_get(bm, x, y),
+                    Self::bm_get(bm, x + 1, y),
+                    Self::bm_get(bm, x, y + 1),
+                    Self::bm_get(bm, x + 1, y + 1),
+                );
+
+                out += match m {
+                    (false, false, false, false) => " ",
+                    (true, false, false, false) => "▘",
+                    (false, true, false, false) => "▝",
+                    (true, true, false, false) => "▀",
+                    (false, false, true, false) => "▖",
+                    (true, false, true, false) => "▌",
+                    (false, true, true, false) => "▞",
+                    (true, true, true, false) => "▛",
+                    (false, false, false, true) => "▗",
+                    (true, false, false, true) => "▚",
+                    (false, true, false, true) => "▐",
+                    (true, true, false, true) => "▜",
+                    (false, false, true, true) => "▄",
+                    (true, false, true, true) => "▙",
+                    (false, true, true, true) => "▟",
+                    (true, true, true, true) => "█",
+                }
+            }
+        }
+
+        Self::append_per_line(out, "\t\t")
+    }
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::String(s) => write!(f, "{}", s),
+            Self::Bitmap(bm) => {
+                writeln!(f).unwrap();
+                write!(f, "{}", Self::minify_bitmap(bm))
+            }
+        }
+    }
+}
+
+impl serde::Serialize for Answer {
+    /// Numbers and strings are serialized as they are,
+    /// bitmaps as an array of rows using `#` for set and `.` for unset pixels.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Number(n) => serializer.serialize_u64(*n),
+            Self::String(s) => serializer.serialize_str(s),
+            Self::Bitmap(bm) => serializer.collect_seq(Self::bitmap_rows(bm)),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Answer {
+    /// Reads answers in the format they are serialized in.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Serialized {
+            Number(u64),
+            String(String),
+            Bitmap(Vec<String>),
+        }
+
+        Ok(match Serialized::deserialize(deserializer)? {
+            Serialized::Number(n) => Self::Number(n),
+            Serialized::String(s) => Self::String(s),
+            Serialized::Bitmap(rows) => Self::Bitmap(
+                rows.iter()
+                    .map(|row| row.chars().map(|v| v == '#').collect())
+                    .collect(),
+            ),
+        })
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Self::Number(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+/// An error raised while parsing the input or computing a solution.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolveError {
+    /// The input could not be parsed.
+    /// The position is 1-based and only known if the parser could determine it.
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+    /// The input was parsed, but no solution could be found for it.
+    Unsolvable(String),
+}
+
+#[allow(dead_code)]
+impl SolveError {
+    /// Creates a parse error without any position information.
+    pub fn parse<S: Into<String>>(message: S) -> Self {
+        Self::Parse {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for an input without a solution.
+    pub fn unsolvable<S: Into<String>>(message: S) -> Self {
+        Self::Unsolvable(message.into())
+    }
+
+    /// Sets the line of a parse error, unless it already has one.
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            Self::Parse {
+                line: None,
+                column,
+                message,
+            } => Self::Parse {
+                line: Some(line),
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    /// Sets the column of a parse error, unless it already has one.
+    pub fn at_column(self, column: usize) -> Self {
+        match self {
+            Self::Parse {
+                line,
+                column: None,
+                message,
+            } => Self::Parse {
+                line,
+                column: Some(column),
+                message,
+            },
+            other => other,
+        }
+    }
+
+    /// Moves the line of a parse error down by `offset` lines.
+    /// Used when a part of the input was parsed on its own.
+    pub fn offset_lines(self, offset: usize) -> Self {
+        match self {
+            Self::Parse {
+                line: Some(line),
+                column,
+                message,
+            } => Self::Parse {
+                line: Some(line + offset),
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Parse {
+                line: Some(line),
+                column: Some(column),
+                message,
+            } => write!(
+                f,
+                "invalid input at line {}, column {}: {}",
+                line, column, message
+            ),
+            Self::Parse {
+                line: Some(line),
+                column: None,
+                message,
+            } => write!(f, "invalid input at line {}: {}", line, message),
+            Self::Parse { message, .. } => write!(f, "invalid input: {}", message),
+            Self::Unsolvable(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Parses every line of the input using `parser`, attaching the line number to any error.
+#[allow(dead_code)]
+pub fn parse_lines<'a, T, F>(input: &'a str, mut parser: F) -> Result<Vec<T>, SolveError>
+where
+    F: FnMut(&'a str) -> Result<T, SolveError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| parser(line).map_err(|e| e.at_line(y + 1)))
+        .collect()
+}
+
+/// Parses every block of the input separated by an empty line using `parser`.
+/// Line numbers of errors are relative to the block and get corrected to the whole input.
+#[allow(dead_code)]
+pub fn parse_blocks<'a, T, F>(input: &'a str, mut parser: F) -> Result<Vec<T>, SolveError>
+where
+    F: FnMut(&'a str) -> Result<T, SolveError>,
+{
+    let mut offset = 0;
+    input
+        .split("\n\n")
+        .map(|block| {
+            let parsed = parser(block).map_err(|e| e.offset_lines(offset));
+            offset += block.lines().count() + 1;
+            parsed
+        })
+        .collect()
+}
+
+/// Parses a number, turning a failure into a [`SolveError`].
+#[allow(dead_code)]
+pub fn parse_number<N>(text: &str) -> Result<N, SolveError>
+where
+    N: std::str::FromStr,
+{
+    text.trim()
+        .parse()
+        .map_err(|_| SolveError::parse(format!("expected a number, found '{}'", text)))
+}
+
+/// A named example input, with the answers expected for each part.
+/// Parts without a known answer are skipped when testing against this input.
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub one: Option<Answer>,
+    pub two: Option<Answer>,
+}
+
+/// Whether a part passed, failed or wasn't tested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    /// There is no known answer to compare against, so the part wasn't run.
+    Skipped,
+}
+
+impl std::fmt::Display for TestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Passed => write!(f, "PASSED"),
+            Self::Failed => write!(f, "FAILED"),
+            Self::Skipped => write!(f, "SKIPPED"),
+        }
+    }
+}
+
+/// The outcome of testing one part against one test case.
+/// `result` is `None` if the part was skipped.
+#[derive(Debug, Clone)]
+pub struct CaseResult {
+    pub case: &'static str,
+    pub part: u8,
+    pub expected: Option<Answer>,
+    pub result: Option<Result<Answer, SolveError>>,
+    /// How long parsing the input and solving the part took together.
+    pub duration: Duration,
+}
+
+impl CaseResult {
+    pub(crate) fn run<F>(case: &'static str, part: u8, expected: Option<Answer>, solve: F) -> Self
+    where
+        F: FnOnce() -> Result<Answer, SolveError>,
+    {
+        let start = Instant::now();
+        let result = expected.as_ref().map(|_| solve());
+        let duration = start.elapsed();
+
+        Self {
+            case,
+            part,
+            expected,
+            result,
+            duration,
+        }
+    }
+
+    pub fn status(&self) -> TestStatus {
+        match (&self.result, &self.expected) {
+            (Some(Ok(answer)), Some(expected)) if answer == expected => TestStatus::Passed,
+            (Some(_), _) => TestStatus::Failed,
+            (None, _) => TestStatus::Skipped,
+        }
+    }
+
+    /// Whether the part didn't fail. Skipped parts count as passed.
+    pub fn passed(&self) -> bool {
+        self.status() != TestStatus::Failed
+    }
+}
+
+/// How far a part of a day has been solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    /// There is a solution, but it doesn't produce the answer yet, e.g. because it's too slow.
+    InProgress,
+    NotStarted,
+}
+
+impl std::fmt::Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Solved => write!(f, "solved"),
+            Self::InProgress => write!(f, "in progress"),
+            Self::NotStarted => write!(f, "not started"),
+        }
+    }
+}
+
+pub trait DayImpl<T>
+where
+    T: Clone,
+{
+    /// Whether this day has been solved, or is still the unmodified template.
+    const IMPLEMENTED: bool = true;
+
+    /// The title of the puzzle, if it has been filled in.
+    const TITLE: Option<&'static str> = None;
+
+    /// Topics of the puzzle or its solution, like `"grid"` or `"pathfinding"`.
+    const TAGS: &'static [&'static str] = &[];
+
+    /// How far each of the parts has been solved.
+    const STATUS: [PartStatus; 2] = if Self::IMPLEMENTED {
+        [PartStatus::Solved; 2]
+    } else {
+        [PartStatus::NotStarted; 2]
+    };
+
+    /// The example inputs this day is tested against.
+    fn test_cases() -> Vec<TestCase>;
+
+    /// Parse input
+    fn init(input: &str) -> Result<(Self, T), SolveError>
+    where
+        Self: Sized;
+
+    /// Compute part 1
+    fn one(&self, data: &mut T) -> Result<Answer, SolveError>;
+
+    /// Compute part 2
+    fn two(&self, data: &mut T) -> Result<Answer, SolveError>;
+
+    /// Parse input and measure the time it took
+    fn init_timed(input: &str) -> Result<((Self, T), Duration), SolveError>
+    where
+        Self: Sized,
+    {
+        let s = Instant::now();
+        let parsed = Self::init(input)?;
+        Ok((parsed, s.elapsed()))
+    }
+
+    /// Compute part 1 and measure the time it took
+    fn one_timed(&self, data: &mut T) -> Result<(Answer, Duration), SolveError> {
+        let s = Instant::now();
+        let one = self.one(data)?;
+        Ok((one, s.elapsed()))
+    }
+
+    /// Compute part 2 and measure the time it took
+    fn two_timed(&self, data: &mut T) -> Result<(Answer, Duration), SolveError> {
+        let s = Instant::now();
+        let two = self.two(data)?;
+        Ok((two, s.elapsed()))
+    }
+
+    /// Compute both parts
+    #[allow(dead_code)]
+    fn run(input: &str) -> Result<(Answer, Answer), SolveError>
+    where
+        Self: Sized,
+    {
+        let (day, mut data) = Self::init(input)?;
+        Ok((day.one(&mut data.clone())?, day.two(&mut data)?))
+    }
+
+    /// Init and compute part 1
+    #[allow(dead_code)]
+    fn run_one(input: &str) -> Result<Answer, SolveError>
+    where
+        Self: Sized,
+    {
+        let (day, mut data) = Self::init(input)?;
+        day.one(&mut data)
+    }
+
+    /// Init and compute part 2
+    #[allow(dead_code)]
+    fn run_two(input: &str) -> Result<Answer, SolveError>
+    where
+        Self: Sized,
+    {
+        let (day, mut data) = Self::init(input)?;
+        day.two(&mut data)
+    }
+
+    /// Init and compute part 1
+    fn run_one_timed(input: &str) -> Result<(Answer, Duration, Duration), SolveError>
+    where
+        Self: Sized,
+    {
+        let ((day, mut data), init_t) = Self::init_timed(input)?;
+        let (one, one_t) = day.one_timed(&mut data)?;
+        Ok((one, init_t, one_t))
+    }
+
+    /// Init and compute part 2
+    fn run_two_timed(input: &str) -> Result<(Answer, Duration, Duration), SolveError>
+    where
+        Self: Sized,
+    {
+        let ((day, mut data), init_t) = Self::init_timed(input)?;
+        let (two, two_t) = day.two_timed(&mut data)?;
+        Ok((two, init_t, two_t))
+    }
+
+    /// Compute both parts, and measure the time each step took
+    #[allow(clippy::type_complexity)]
+    fn run_timed(input: &str) -> Result<(Answer, Answer, Duration, Duration, Duration), SolveError>
+    where
+        Self: Sized,
+    {
+        let ((day, mut data), i_t) = Self::init_timed(input)?;
+        let (one, one_t) = day.one_timed(&mut data.clone())?;
+        let (two, two_t) = day.two_timed(&mut data)?;
+
+        Ok((one, two, i_t, one_t, two_t))
+    }
+
+    /// Test part one against every test case
+    fn test_one() -> Vec<CaseResult>
+    where
+        Self: Sized,
+    {
+        Self::test_cases()
+            .into_iter()
+            .map(|case| {
+                CaseResult::run(case.name, 1, case.one, || {
+                    let (day, mut data) = Self::init(case.input)?;
+                    day.one(&mut data)
+                })
+            })
+            .collect()
+    }
+
+    /// Test part two against every test case
+    fn test_two() -> Vec<CaseResult>
+    where
+        Self: Sized,
+    {
+        Self::test_cases()
+            .into_iter()
+            .map(|case| {
+                CaseResult::run(case.name, 2, case.two, || {
+                    let (day, mut data) = Self::init(case.input)?;
+                    day.two(&mut data)
+                })
+            })
+            .collect()
+    }
+
+    /// Run both tests on every test case
+    fn test() -> Vec<CaseResult>
+    where
+        Self: Sized,
+    {
+        let mut results = Self::test_one();
+        results.append(&mut Self::test_two());
+        results.sort_by_key(|r| r.part);
+        results
+    }
+}
+
+/// Both answers of a day, followed by the time parsing and each of the parts took.
+/// Parts which weren't run have no answer and took no time.
+pub type DayResult = (Option<Answer>, Option<Answer>, Duration, Duration, Duration);
+
+/// A day with the type its input is parsed into hidden, so that all days can be
+/// kept in one registry and dispatched at runtime.
+pub trait DynDay: Sync {
+    /// The number of the day, 1 to 25.
+    fn day(&self) -> u8;
+
+    /// Whether this day has been solved, or is still the unmodified template.
+    fn implemented(&self) -> bool;
+
+    /// The title of the puzzle, if it has been filled in.
+    fn title(&self) -> Option<&'static str>;
+
+    /// Topics of the puzzle or its solution.
+    fn tags(&self) -> &'static [&'static str];
+
+    /// Whether the puzzle has a second part. The last day only has one.
+    fn has_part_two(&self) -> bool;
+
+    /// How far a part, 1 or 2, has been solved.
+    fn status(&self, part: u8) -> PartStatus;
+
+    /// The example inputs this day is tested against.
+    fn test_cases(&self) -> Vec<TestCase>;
+
+    /// Solves the requested part(s) and measures the time each step took.
+    fn solve(&self, part: &Part, input: &str) -> Result<DayResult, SolveError>;
+
+    /// Tests the requested part(s) against every test case.
+    fn test(&self, part: &Part) -> Vec<CaseResult>;
+}
+
+/// Implements [`DynDay`] for the day `D`, whose input is parsed into `T`.
+/// `T` doesn't have to be named, it is inferred from the day's [`DayImpl`].
+pub struct Registered<D, T> {
+    day: u8,
+    marker: PhantomData<fn() -> (D, T)>,
+}
+
+impl<D, T> Registered<D, T> {
+    pub const fn new(day: u8) -> Self {
+        Self {
+            day,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<D, T> DynDay for Registered<D, T>
+where
+    T: Clone,
+    D: DayImpl<T>,
+{
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn implemented(&self) -> bool {
+        D::IMPLEMENTED
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        D::TITLE
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        D::TAGS
+    }
+
+    fn has_part_two(&self) -> bool {
+        self.day < 25
+    }
+
+    fn status(&self, part: u8) -> PartStatus {
+        let [one, two] = D::STATUS;
+        if part == 1 {
+            one
+        } else {
+            two
+        }
+    }
+
+    fn test_cases(&self) -> Vec<TestCase> {
+        D::test_cases()
+    }
+
+    fn solve(&self, part: &Part, input: &str) -> Result<DayResult, SolveError> {
+        let input = input.trim_end();
+        match part {
+            Part::Both => D::run_timed(input).map(|(one, two, init_t, one_t, two_t)| {
+                (Some(one), Some(two), init_t, one_t, two_t)
+            }),
+            Part::One => D::run_one_timed(input)
+                .map(|(one, init_t, one_t)| (Some(one), None, init_t, one_t, Duration::ZERO)),
+            Part::Two => D::run_two_timed(input)
+                .map(|(two, init_t, two_t)| (None, Some(two), init_t, Duration::ZERO, two_t)),
+        }
+    }
+
+    fn test(&self, part: &Part) -> Vec<CaseResult> {
+        match part {
+            Part::Both => D::test(),
+            Part::One => D::test_one(),
+            Part::Two => D::test_two(),
+        }
+    }
+}
+
+/// The solutions of one Advent of Code event.
+pub struct Event {
+    pub year: u16,
+    days: &'static [&'static dyn DynDay],
+}
+
+impl Event {
+    /// Creates an event from its days, which have to be ordered by their number.
+    pub const fn new(year: u16, days: &'static [&'static dyn DynDay]) -> Self {
+        Self { year, days }
+    }
+
+    /// All days, ordered by their number.
+    pub fn days(&self) -> &'static [&'static dyn DynDay] {
+        self.days
+    }
+
+    /// Looks up a day by its number.
+    pub fn get_day(&self, day: u8) -> Option<&'static dyn DynDay> {
+        self.days.iter().find(|d| d.day() == day).copied()
+    }
+
+    /// Whether a day has been solved, as opposed to still being the template.
+    pub fn is_day_implemented(&self, day: u8) -> bool {
+        self.get_day(day).is_some_and(|d| d.implemented())
+    }
+
+    /// All days that have been solved so far.
+    pub fn implemented_days(&self) -> Vec<u8> {
+        self.days
+            .iter()
+            .filter(|d| d.implemented())
+            .map(|d| d.day())
+            .collect()
+    }
+}
@@ -0,0 +1,200 @@
+use crate::cache::{load_json, save_json};
+use crate::Verdict;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// An answer which was submitted, and how the server reacted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When the answer was submitted, in seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+/// All submissions, by day and then by part.
+pub type History = BTreeMap<u8, BTreeMap<u8, Vec<Submission>>>;
+
+/// What is known about an answer from previous submissions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerStatus {
+    /// The answer was accepted.
+    Accepted,
+    /// The answer was rejected before, or another answer was accepted.
+    KnownWrong,
+    /// The answer is at least as high as one that was too high,
+    /// or at most as low as one that was too low.
+    OutOfBounds(String),
+    /// Nothing is known about this answer yet.
+    New,
+}
+
+impl std::fmt::Display for AnswerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Accepted => write!(f, "accepted"),
+            Self::KnownWrong => write!(f, "known wrong"),
+            Self::OutOfBounds(reason) => write!(f, "known wrong, {}", reason),
+            Self::New => write!(f, "new"),
+        }
+    }
+}
+
+/// Loads the submission history from a file. A missing file results in an empty history.
+pub fn load_history(path: &Path) -> io::Result<History> {
+    load_json(path)
+}
+
+/// Stores the submission history in a file.
+pub fn save_history(path: &Path, history: &History) -> io::Result<()> {
+    save_json(path, history)
+}
+
+/// Adds a submission to the history.
+pub fn add_submission(history: &mut History, day: u8, part: u8, answer: &str, verdict: Verdict) {
+    history
+        .entry(day)
+        .or_default()
+        .entry(part)
+        .or_default()
+        .push(Submission {
+            answer: answer.to_owned(),
+            verdict,
+            submitted_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|v| v.as_secs())
+                .unwrap_or_default(),
+        });
+}
+
+/// Checks an answer against the previous submissions of the part.
+/// Rate limited submissions and other responses without a verdict are ignored.
+pub fn answer_status(history: &History, day: u8, part: u8, answer: &str) -> AnswerStatus {
+    let submissions = match history.get(&day).and_then(|v| v.get(&part)) {
+        Some(submissions) => submissions,
+        None => return AnswerStatus::New,
+    };
+
+    if let Some(accepted) = submissions.iter().find(|v| v.verdict == Verdict::Correct) {
+        return if accepted.answer == answer {
+            AnswerStatus::Accepted
+        } else {
+            AnswerStatus::KnownWrong
+        };
+    }
+
+    if submissions.iter().any(|v| {
+        v.answer == answer
+            && matches!(
+                v.verdict,
+                Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+            )
+    }) {
+        return AnswerStatus::KnownWrong;
+    }
+
+    if let Ok(number) = answer.parse::<i128>() {
+        for submission in submissions {
+            let bound = match submission.answer.parse::<i128>() {
+                Ok(bound) => bound,
+                Err(_) => continue,
+            };
+            match submission.verdict {
+                Verdict::TooHigh if number >= bound => {
+                    return AnswerStatus::OutOfBounds(format!("{} was too high", bound))
+                }
+                Verdict::TooLow if number <= bound => {
+                    return AnswerStatus::OutOfBounds(format!("{} was too low", bound))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    AnswerStatus::New
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(submissions: &[(&str, Verdict)]) -> History {
+        let mut history = History::new();
+        for (answer, verdict) in submissions {
+            add_submission(&mut history, 5, 1, answer, verdict.clone());
+        }
+        history
+    }
+
+    #[test]
+    fn knows_nothing_about_other_parts() {
+        let history = history(&[("42", Verdict::Correct)]);
+
+        assert_eq!(answer_status(&history, 5, 2, "42"), AnswerStatus::New);
+        assert_eq!(answer_status(&history, 6, 1, "42"), AnswerStatus::New);
+    }
+
+    #[test]
+    fn knows_accepted_answers() {
+        let history = history(&[("40", Verdict::TooLow), ("42", Verdict::Correct)]);
+
+        assert_eq!(answer_status(&history, 5, 1, "42"), AnswerStatus::Accepted);
+        assert_eq!(
+            answer_status(&history, 5, 1, "41"),
+            AnswerStatus::KnownWrong
+        );
+        assert_eq!(
+            answer_status(&history, 5, 1, "40"),
+            AnswerStatus::KnownWrong
+        );
+    }
+
+    #[test]
+    fn knows_wrong_answers() {
+        let history = history(&[("abc", Verdict::Incorrect), ("50", Verdict::TooHigh)]);
+
+        assert_eq!(
+            answer_status(&history, 5, 1, "abc"),
+            AnswerStatus::KnownWrong
+        );
+        assert_eq!(
+            answer_status(&history, 5, 1, "50"),
+            AnswerStatus::KnownWrong
+        );
+        assert_eq!(answer_status(&history, 5, 1, "abd"), AnswerStatus::New);
+    }
+
+    #[test]
+    fn knows_the_bounds() {
+        let history = history(&[("50", Verdict::TooHigh), ("10", Verdict::TooLow)]);
+
+        assert_eq!(
+            answer_status(&history, 5, 1, "51"),
+            AnswerStatus::OutOfBounds("50 was too high".to_owned())
+        );
+        assert_eq!(
+            answer_status(&history, 5, 1, "-3"),
+            AnswerStatus::OutOfBounds("10 was too low".to_owned())
+        );
+        assert_eq!(answer_status(&history, 5, 1, "11"), AnswerStatus::New);
+        assert_eq!(answer_status(&history, 5, 1, "49"), AnswerStatus::New);
+        assert_eq!(answer_status(&history, 5, 1, "x"), AnswerStatus::New);
+    }
+
+    #[test]
+    fn ignores_submissions_without_a_verdict() {
+        let history = history(&[
+            ("42", Verdict::RateLimited(None)),
+            ("43", Verdict::WrongLevel),
+            ("44", Verdict::Unknown("?".to_owned())),
+        ]);
+
+        assert_eq!(answer_status(&history, 5, 1, "42"), AnswerStatus::New);
+        assert_eq!(answer_status(&history, 5, 1, "43"), AnswerStatus::New);
+        assert_eq!(answer_status(&history, 5, 1, "44"), AnswerStatus::New);
+        assert_eq!(answer_status(&history, 5, 1, "41"), AnswerStatus::New);
+    }
+}
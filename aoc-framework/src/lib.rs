@@ -0,0 +1,488 @@
+pub use crate::answers::{
+    load_answers, record_answers, save_answers, verify_cached_day, verify_day, Answers,
+    RecordedAnswers,
+};
+pub use crate::bench::{
+    bench_and_print_day, bench_day, compare_with_baseline, load_baseline, save_baseline, Baseline,
+    BenchResult, BenchSettings, PhaseBaseline, PhaseStats,
+};
+pub use crate::cache::{
+    default_cache_dir, input_checksum, migrate_legacy_cache, read_cached_input,
+    remove_cached_input, validate_input, write_cached_input, Cache, CacheEntry, CacheStatus,
+    InvalidInput, CACHE_DIR_VAR, LEGACY_CACHE_DIR,
+};
+pub use crate::calendar::{calendar, print_calendar, CalendarDay};
+pub use crate::client::{
+    AocClient, ClientConfig, DownloadError, Verdict, DEFAULT_BASE_URL, DEFAULT_USER_AGENT,
+    DEFAULT_YEAR,
+};
+pub use crate::config::{
+    load_config_file, load_config_files, user_config_path, Color, Config, ConfigFile, ConfigFiles,
+    Setting, Source, CONFIG_FILE, USER_CONFIG_VAR,
+};
+pub use crate::day::{
+    parse_blocks, parse_lines, parse_number, Answer, CaseResult, DayImpl, DayResult, DynDay, Event,
+    PartStatus, Registered, SolveError, TestCase, TestStatus,
+};
+pub use crate::history::{
+    add_submission, answer_status, load_history, save_history, AnswerStatus, History, Submission,
+};
+pub use crate::output::{format_records, OutputFormat, PartRecord};
+pub use crate::puzzle::{parse_puzzle, Puzzle, PuzzlePart};
+pub use crate::scaffold::{is_stub, render_day, DayScaffold, DAY_TEMPLATE};
+pub use crate::session::{default_session_path, load_session, save_session, SESSION_FILE_VAR};
+pub use crate::test_report::TestReport;
+pub use crate::unlock::{unlock_time, wait_for_unlock, Clock, SystemClock};
+use colored::*;
+use lazy_static::lazy_static;
+use mut_static::MutStatic;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+mod answers;
+mod bench;
+mod cache;
+mod calendar;
+mod client;
+mod config;
+mod day;
+mod history;
+mod output;
+mod puzzle;
+mod scaffold;
+mod session;
+mod test_report;
+#[cfg(test)]
+mod test_utils;
+mod unlock;
+
+/// How much the solutions print while running.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Verbosity {
+    /// Only print the results.
+    None,
+    /// Also print verbose information.
+    Verbose,
+    /// Also print development information.
+    #[serde(rename = "dev", alias = "development")]
+    #[cfg_attr(feature = "clap", value(name = "dev", alias = "development"))]
+    Development,
+}
+
+/// The parts of a day to compute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Part {
+    /// Only the first part.
+    #[serde(rename = "1", alias = "one")]
+    #[cfg_attr(feature = "clap", value(name = "1", alias = "one"))]
+    One,
+    /// Only the second part.
+    #[serde(rename = "2", alias = "two")]
+    #[cfg_attr(feature = "clap", value(name = "2", alias = "two"))]
+    Two,
+    /// Both parts.
+    #[serde(rename = "b", alias = "both")]
+    #[cfg_attr(feature = "clap", value(name = "b", alias = "both"))]
+    Both,
+}
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    verbosity: Verbosity,
+}
+
+impl Settings {
+    fn set_verbosity(&mut self, new: Verbosity) {
+        self.verbosity = new;
+    }
+}
+
+lazy_static! {
+    static ref VERBOSITY: MutStatic<Settings> = MutStatic::from(Settings {
+        verbosity: Verbosity::None
+    });
+}
+
+pub fn set_verbosity(new_verbosity: Verbosity) {
+    let mut handle = VERBOSITY.write().unwrap();
+    handle.set_verbosity(new_verbosity);
+}
+
+pub fn get_verbosity() -> Verbosity {
+    VERBOSITY.read().unwrap().verbosity.clone()
+}
+
+#[macro_export]
+macro_rules! vprintln {
+    ($($arg:tt)*) => {
+        #[cfg(debug_assertions)]
+        if $crate::get_verbosity() ==  $crate::Verbosity::Verbose || $crate::get_verbosity() ==  $crate::Verbosity::Development {
+            println!(
+                $($arg)*
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! vprint {
+    ($($arg:tt)*) => {
+        #[cfg(debug_assertions)]
+        if $crate::get_verbosity() ==  $crate::Verbosity::Verbose || $crate::get_verbosity() ==  $crate::Verbosity::Development {
+            print!(
+                $($arg)*
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! dprintln {
+    ($($arg:tt)*) => {
+        #[cfg(debug_assertions)]
+        if $crate::get_verbosity() == $crate::Verbosity::Development  {
+            println!(
+                $($arg)*
+            )
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! dprint {
+    ($($arg:tt)*) => {
+        #[cfg(debug_assertions)]
+        if $crate::get_verbosity() == $crate::Verbosity::Development  {
+            print!(
+                $($arg)*
+            )
+        }
+    };
+}
+
+pub(crate) fn dynamic_range_time_format(d: &Duration) -> String {
+    let nanos = d.as_nanos();
+
+    if nanos < 1000 {
+        // less than one microsecond
+        format!("{} ns", nanos)
+    } else if nanos < 100000 {
+        // less than 10 microseconds
+        format!("{:.3} µs", nanos as f64 / 1000.0)
+    } else if nanos < 1000000 {
+        // less than one millisecond
+        format!("{} µs", nanos / 1000)
+    } else if nanos < 10000000 {
+        // less than 10 milliseconds
+        format!("{:.3} ms", nanos as f64 / 1000000.0)
+    } else if nanos < 1000000000 {
+        // less than a second
+        format!("{} ms", nanos / 1000000)
+    } else if nanos < 10000000000 {
+        // less than 10 seconds
+        format!("{:.3} s", nanos as f64 / 1000000000.0)
+    } else {
+        // more than 10 seconds
+        format!("{} s", nanos / 1000000000)
+    }
+}
+
+pub(crate) fn print_solve_error(err: &SolveError) {
+    println!("{}:", "Error".red().bold());
+    println!("\t{}", format!("{}", err).bold().red());
+}
+
+/// Solves a single part of a day, 1 or 2.
+pub fn solve_part(day: &dyn DynDay, part: u8, input: &str) -> Result<Answer, SolveError> {
+    let part = if part == 1 { Part::One } else { Part::Two };
+    let (one, two, _, _, _) = day.solve(&part, input)?;
+
+    Ok(one.or(two).expect("The requested part wasn't run."))
+}
+
+/// What the submission history knows about an answer, if it can be submitted at all.
+fn history_status(history: &History, day: u8, part: u8, answer: &Answer) -> Option<AnswerStatus> {
+    answer
+        .submission()
+        .map(|submission| answer_status(history, day, part, &submission))
+}
+
+/// Colors the note of an answer's status, e.g. " (accepted)".
+fn colored_note(status: &AnswerStatus) -> String {
+    let note = format!(" ({})", status);
+    match status {
+        AnswerStatus::Accepted => note.green().to_string(),
+        AnswerStatus::New => note.yellow().to_string(),
+        _ => note.red().to_string(),
+    }
+}
+
+/// Describes what the submission history knows about an answer, e.g. " (accepted)".
+fn history_note(history: &History, day: u8, part: u8, answer: &Answer) -> String {
+    history_status(history, day, part, answer)
+        .map(|status| colored_note(&status))
+        .unwrap_or_default()
+}
+
+/// Runs the requested part(s) of a day and prints the results,
+/// noting whether the answers were accepted or rejected before.
+/// Returns false if the input couldn't be parsed or solved.
+pub fn run_day(day: &dyn DynDay, part: Part, input: &str, history: &History) -> bool {
+    println!("{} Day {}", "Starting".green().bold(), day.day());
+    println!("{}", "-----------------------".green().bold());
+
+    let (one, two, init_t, one_t, two_t) = match day.solve(&part, input) {
+        Ok(result) => result,
+        Err(err) => {
+            print_solve_error(&err);
+            return false;
+        }
+    };
+
+    println!("{}:", "Results".green().bold());
+    println!(
+        "\t{}: {}",
+        "Parsing time".green(),
+        dynamic_range_time_format(&init_t).bold().blue()
+    );
+    if let Some(one) = one {
+        println!("\t{}:", "Part 1".green());
+        println!(
+            "\t\tSolution: {}{}",
+            format!("{}", one).bold().blue(),
+            history_note(history, day.day(), 1, &one)
+        );
+        println!(
+            "\t\tTook:     {}",
+            if cfg!(debug_assertions) {
+                (dynamic_range_time_format(&one_t) + " (DEBUG)")
+                    .bold()
+                    .red()
+            } else {
+                dynamic_range_time_format(&one_t).bold().blue()
+            }
+        );
+    }
+    if let Some(two) = two {
+        println!("\t{}:", "Part 2".green());
+        println!(
+            "\t\tSolution: {}{}",
+            format!("{}", two).bold().blue(),
+            history_note(history, day.day(), 2, &two)
+        );
+        println!(
+            "\t\tTook:     {}",
+            if cfg!(debug_assertions) {
+                (dynamic_range_time_format(&two_t) + " (DEBUG)")
+                    .bold()
+                    .red()
+            } else {
+                dynamic_range_time_format(&two_t).bold().blue()
+            }
+        );
+    }
+
+    true
+}
+
+fn table_answer(answer: &Answer) -> String {
+    match answer {
+        // Bitmaps span multiple lines, which would break the table.
+        Answer::Bitmap(_) => "(bitmap)".to_owned(),
+        other => format!("{}", other),
+    }
+}
+
+/// Runs multiple days, each with its own input, and prints a table of all
+/// answers, noting what the submission history knows about them, and timings
+/// followed by the total runtime.
+/// Returns false if any of the days couldn't be parsed or solved.
+pub fn run_days(days: &[(&dyn DynDay, String)], part: Part, history: &History) -> bool {
+    println!("{} {} days", "Starting".green().bold(), days.len());
+    println!("{}", "-----------------------".green().bold());
+
+    let header = [
+        "Day",
+        "Part 1",
+        "Part 2",
+        "Parsing",
+        "Part 1 took",
+        "Part 2 took",
+        "Total",
+    ]
+    .map(|v| v.to_owned());
+    let mut rows: Vec<[String; 7]> = vec![];
+    // The status of the answers of each row, noted next to them.
+    let mut statuses: Vec<[Option<AnswerStatus>; 2]> = vec![];
+    let mut errors: Vec<(u8, SolveError)> = vec![];
+    let mut total = Duration::ZERO;
+
+    for (day, input) in days {
+        let (day, result) = (day.day(), day.solve(&part, input));
+        let row = match result {
+            Ok((one, two, init_t, one_t, two_t)) => {
+                total += init_t + one_t + two_t;
+                statuses.push([
+                    one.as_ref()
+                        .and_then(|v| history_status(history, day, 1, v)),
+                    two.as_ref()
+                        .and_then(|v| history_status(history, day, 2, v)),
+                ]);
+                [
+                    format!("{}", day),
+                    one.as_ref().map(table_answer).unwrap_or("-".to_owned()),
+                    two.as_ref().map(table_answer).unwrap_or("-".to_owned()),
+                    dynamic_range_time_format(&init_t),
+                    match part {
+                        Part::Two => "-".to_owned(),
+                        _ => dynamic_range_time_format(&one_t),
+                    },
+                    match part {
+                        Part::One => "-".to_owned(),
+                        _ => dynamic_range_time_format(&two_t),
+                    },
+                    dynamic_range_time_format(&(init_t + one_t + two_t)),
+                ]
+            }
+            Err(err) => {
+                errors.push((day, err));
+                statuses.push([None, None]);
+                let mut row: [String; 7] = std::array::from_fn(|_| "-".to_owned());
+                row[0] = format!("{}", day);
+                row[1] = "ERROR".to_owned();
+                row[2] = "ERROR".to_owned();
+                row
+            }
+        };
+        rows.push(row);
+    }
+
+    let note_length = |status: &Option<AnswerStatus>| {
+        status
+            .as_ref()
+            .map_or(0, |v| format!(" ({})", v).chars().count())
+    };
+    let mut widths = header.clone().map(|v| v.chars().count());
+    for (row, status) in rows.iter().zip(&statuses) {
+        for (column, (width, cell)) in widths.iter_mut().zip(row).enumerate() {
+            let note = match column {
+                1 | 2 => note_length(&status[column - 1]),
+                _ => 0,
+            };
+            *width = (*width).max(cell.chars().count() + note);
+        }
+    }
+
+    println!("{}:", "Results".green().bold());
+    let line = header
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect::<Vec<String>>()
+        .join(" | ");
+    println!("\t{}", line.green());
+    for (row, status) in rows.iter().zip(&statuses) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| match column {
+                0 => format!("{:<width$}", cell, width = width)
+                    .green()
+                    .to_string(),
+                1 | 2 if cell == "ERROR" => format!("{:<width$}", cell, width = width)
+                    .red()
+                    .bold()
+                    .to_string(),
+                1 | 2 if status[column - 1].is_some() => {
+                    let status = &status[column - 1];
+                    let padding = width - cell.chars().count() - note_length(status);
+                    format!(
+                        "{}{}{}",
+                        cell.bold().blue(),
+                        status.as_ref().map(colored_note).unwrap_or_default(),
+                        " ".repeat(padding)
+                    )
+                }
+                _ => format!("{:<width$}", cell, width = width)
+                    .bold()
+                    .blue()
+                    .to_string(),
+            })
+            .collect();
+        println!("\t{}", cells.join(" | "));
+    }
+
+    for (day, err) in &errors {
+        println!(
+            "\t{} {}: {}",
+            "Day".red(),
+            format!("{}", day).red(),
+            format!("{}", err).bold().red()
+        );
+    }
+
+    println!(
+        "\t{}: {}",
+        "Total runtime".green(),
+        if cfg!(debug_assertions) {
+            (dynamic_range_time_format(&total) + " (DEBUG)")
+                .bold()
+                .red()
+        } else {
+            dynamic_range_time_format(&total).bold().blue()
+        }
+    );
+
+    errors.is_empty()
+}
+
+fn part_numbers(part: &Part) -> Vec<u8> {
+    match part {
+        Part::One => vec![1],
+        Part::Two => vec![2],
+        Part::Both => vec![1, 2],
+    }
+}
+
+/// Runs multiple days, each with its own input, and collects a record for every part run.
+pub fn run_days_records(days: &[(&dyn DynDay, String)], part: Part) -> Vec<PartRecord> {
+    let mut records = vec![];
+
+    for (day, input) in days {
+        let (day, result) = (day.day(), day.solve(&part, input));
+        match result {
+            Ok((one, two, init_t, one_t, two_t)) => {
+                for n in part_numbers(&part) {
+                    let (answer, part_t) = match n {
+                        1 => (one.clone(), one_t),
+                        _ => (two.clone(), two_t),
+                    };
+                    let mut record = PartRecord::new(day, n).timed(init_t, part_t);
+                    record.answer = answer;
+                    records.push(record);
+                }
+            }
+            Err(err) => {
+                for n in part_numbers(&part) {
+                    let mut record = PartRecord::new(day, n);
+                    record.error = Some(format!("{}", err));
+                    records.push(record);
+                }
+            }
+        }
+    }
+
+    records
+}
+
+/// Tests the requested part(s) of a day against all of its test cases.
+pub fn test_day(day: &dyn DynDay, part: Part) -> TestReport {
+    TestReport {
+        day: day.day(),
+        results: day.test(&part),
+    }
+}
@@ -0,0 +1,127 @@
+use crate::day::Answer;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The format results are printed in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum OutputFormat {
+    /// Colored, human readable text.
+    Text,
+    /// A JSON array of [`PartRecord`]s.
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "A JSON array with one record per part")
+    )]
+    Json,
+    /// CSV with a header line and one [`PartRecord`] per line.
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "CSV with a header line and one record per part")
+    )]
+    Csv,
+}
+
+/// The result of a single part of a day, as emitted by the machine readable output formats.
+///
+/// Fields which don't apply are `None`, e.g. `expected` and `passed` outside of tests
+/// or for skipped parts, or `answer` if the day couldn't be solved. When both parts
+/// are run, they share the same parse time. `case` names the test case a test record belongs to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartRecord {
+    pub day: u8,
+    pub part: u8,
+    pub case: Option<String>,
+    pub answer: Option<Answer>,
+    pub expected: Option<Answer>,
+    pub passed: Option<bool>,
+    pub parse_time_ns: Option<u64>,
+    pub part_time_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl PartRecord {
+    pub(crate) fn new(day: u8, part: u8) -> Self {
+        Self {
+            day,
+            part,
+            case: None,
+            answer: None,
+            expected: None,
+            passed: None,
+            parse_time_ns: None,
+            part_time_ns: None,
+            error: None,
+        }
+    }
+
+    pub(crate) fn timed(mut self, parse_time: Duration, part_time: Duration) -> Self {
+        self.parse_time_ns = Some(parse_time.as_nanos() as u64);
+        self.part_time_ns = Some(part_time.as_nanos() as u64);
+        self
+    }
+
+    /// Whether the part was solved without an error and, if it was tested, passed.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none() && self.passed != Some(false)
+    }
+}
+
+const CSV_HEADER: &str = "day,part,case,answer,expected,passed,parse_time_ns,part_time_ns,error";
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Answers are written as in text output, except that bitmap rows are separated by newlines.
+fn csv_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(Answer::Bitmap(bm)) => csv_field(&Answer::bitmap_rows(bm).join("\n")),
+        Some(answer) => csv_field(&format!("{}", answer)),
+        None => String::new(),
+    }
+}
+
+fn csv_option<T: ToString>(value: &Option<T>) -> String {
+    value
+        .as_ref()
+        .map(|v| csv_field(&v.to_string()))
+        .unwrap_or_default()
+}
+
+/// Serializes the records in the given machine readable format.
+///
+/// # Panics
+/// If called with [`OutputFormat::Text`], which has no record representation.
+pub fn format_records(records: &[PartRecord], format: &OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(records).expect("Failed to serialize records.")
+        }
+        OutputFormat::Csv => {
+            let mut out = CSV_HEADER.to_owned();
+            for record in records {
+                out += "\n";
+                out += &[
+                    record.day.to_string(),
+                    record.part.to_string(),
+                    csv_option(&record.case),
+                    csv_answer(&record.answer),
+                    csv_answer(&record.expected),
+                    csv_option(&record.passed),
+                    csv_option(&record.parse_time_ns),
+                    csv_option(&record.part_time_ns),
+                    csv_option(&record.error),
+                ]
+                .join(",");
+            }
+            out
+        }
+        OutputFormat::Text => panic!("Text output has no record representation."),
+    }
+}
@@ -0,0 +1,277 @@
+/// The examples and answers found in the description of one part of a puzzle.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PuzzlePart {
+    /// The contents of all `<pre><code>` blocks, which are example inputs or illustrations.
+    pub examples: Vec<String>,
+    /// The numbers emphasized using `<code><em>`, which are usually the answers of the examples.
+    /// The last one is most likely the answer of the whole example.
+    pub answers: Vec<String>,
+}
+
+/// A puzzle description, parsed from the puzzle's page.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Puzzle {
+    /// The description of all unlocked parts, converted to plain text.
+    pub text: String,
+    pub parts: Vec<PuzzlePart>,
+}
+
+/// Replaces the HTML entities used on the puzzle pages with the characters they stand for.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| (&rest[1..end], end));
+        let character = entity.and_then(|(name, _)| match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => name
+                    .strip_prefix('#')
+                    .and_then(|v| v.parse().ok())
+                    .and_then(char::from_u32),
+            },
+        });
+
+        match (character, entity) {
+            (Some(character), Some((_, end))) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// A piece of HTML.
+enum Node<'a> {
+    /// Text, with the entities decoded.
+    Text(String),
+    /// The lowercase name of a tag, e.g. `"p"` or `"/p"`.
+    Tag(&'a str),
+}
+
+/// Splits HTML into text and tags.
+fn walk_html(html: &str, mut on_node: impl FnMut(Node)) {
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        if start > 0 {
+            on_node(Node::Text(decode_entities(&rest[..start])));
+        }
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => {
+                rest = &rest[start..];
+                break;
+            }
+        };
+
+        let tag = &rest[start + 1..end];
+        let name_end = tag
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| !c.is_ascii_alphanumeric())
+            .map_or(tag.len(), |(i, _)| i);
+        on_node(Node::Tag(&tag[..name_end].to_ascii_lowercase()));
+        rest = &rest[end + 1..];
+    }
+
+    if !rest.is_empty() {
+        on_node(Node::Text(decode_entities(rest)));
+    }
+}
+
+/// The text of an HTML fragment, without any tags.
+fn inner_text(html: &str) -> String {
+    let mut text = String::new();
+    walk_html(html, |node| {
+        if let Node::Text(chunk) = node {
+            text.push_str(&chunk);
+        }
+    });
+    text
+}
+
+/// Finds the contents of all elements starting with `open` and ending with `close`.
+fn find_all<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        let content = &rest[start + open.len()..];
+        match content.find(close) {
+            Some(end) => {
+                found.push(&content[..end]);
+                rest = &content[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+
+    found
+}
+
+/// Converts the description of a part to text which is readable in a terminal.
+/// Paragraphs are separated by empty lines, list items are prefixed with `-`
+/// and code blocks are indented.
+fn article_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut preformatted = false;
+
+    walk_html(html, |node| match node {
+        Node::Text(chunk) if preformatted => {
+            for (i, line) in chunk.split('\n').enumerate() {
+                if i > 0 {
+                    text.push('\n');
+                }
+                if !line.is_empty() {
+                    if text.ends_with('\n') {
+                        text.push_str("    ");
+                    }
+                    text.push_str(line);
+                }
+            }
+        }
+        Node::Text(chunk) => {
+            let collapsed = chunk.split_whitespace().collect::<Vec<_>>().join(" ");
+            let separated = text.is_empty() || text.ends_with('\n') || text.ends_with(' ');
+            if chunk.starts_with(char::is_whitespace) && !separated {
+                text.push(' ');
+            }
+            text.push_str(&collapsed);
+            if chunk.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                text.push(' ');
+            }
+        }
+        Node::Tag("pre") => {
+            preformatted = true;
+            text.push_str("    ");
+        }
+        Node::Tag("/pre") => {
+            preformatted = false;
+            text.truncate(text.trim_end().len());
+            text.push_str("\n\n");
+        }
+        Node::Tag("li") => text.push_str("  - "),
+        Node::Tag("/li" | "br") => {
+            text.truncate(text.trim_end_matches(' ').len());
+            text.push('\n');
+        }
+        Node::Tag("/h2" | "/p" | "/ul") => {
+            text.truncate(text.trim_end().len());
+            text.push_str("\n\n");
+        }
+        Node::Tag(_) => {}
+    });
+
+    text.trim_end().to_owned()
+}
+
+/// Parses the HTML page of a puzzle: its description, the example blocks and the example answers.
+pub fn parse_puzzle(html: &str) -> Puzzle {
+    let articles = find_all(html, "<article class=\"day-desc\">", "</article>");
+
+    Puzzle {
+        text: articles
+            .iter()
+            .map(|article| article_to_text(article))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        parts: articles
+            .iter()
+            .map(|article| PuzzlePart {
+                examples: find_all(article, "<pre><code>", "</code></pre>")
+                    .into_iter()
+                    .map(inner_text)
+                    .collect(),
+                answers: find_all(article, "<code><em>", "</em></code>")
+                    .into_iter()
+                    .map(|v| inner_text(v).trim().to_owned())
+                    .filter(|v| v.parse::<i64>().is_ok())
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("test_fixtures/puzzle01.html");
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp;&amp; &#35;&#x41; &unknown; & c"),
+            "a <b> && #A &unknown; & c"
+        );
+    }
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let puzzle = parse_puzzle(FIXTURE);
+
+        assert_eq!(
+            puzzle.parts,
+            vec![
+                PuzzlePart {
+                    examples: vec!["1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".to_owned()],
+                    answers: vec!["142".to_owned()],
+                },
+                PuzzlePart {
+                    examples: vec!["two1nine\neightwothree\nabcone2three<xyz>\n".to_owned()],
+                    answers: vec!["125".to_owned()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_descriptions_to_text() {
+        let text = parse_puzzle(FIXTURE).text;
+
+        assert!(text.starts_with("--- Day 1: Trebuchet?! ---\n\nSomething is wrong"));
+        assert!(text.contains(
+            "For example:\n\n    1abc2\n    pqr3stu8vwx\n    a1b2c3d4e5f\n    treb7uchet\n\nIn this example, the calibration values of these four lines are 12, 38, 15, and 77. Adding these together produces 142.\n\n"
+        ));
+        assert!(text.contains("--- Part Two ---"));
+        assert!(text.contains("abcone2three<xyz>"));
+        assert!(text.contains("are 29, 83 & 13."));
+        assert!(text.contains(
+            "  - Values which aren't numbers, like one, are ignored.\n  - Adding these together produces 125.\n\n"
+        ));
+        assert!(!text.contains("\n\n\n"));
+        // Only the descriptions are included, not the rest of the page.
+        assert!(!text.contains("54331"));
+        assert!(!text.contains("Advent of Code"));
+        assert!(text.ends_with("What is the sum of all of the calibration values?"));
+    }
+
+    #[test]
+    fn parses_locked_pages() {
+        assert_eq!(
+            parse_puzzle("<html><body>404 Not Found</body></html>"),
+            Puzzle::default()
+        );
+    }
+}
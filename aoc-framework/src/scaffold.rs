@@ -0,0 +1,108 @@
+/// The template new days are generated from, unless another one is configured.
+pub const DAY_TEMPLATE: &str = include_str!("templates/day.rs.template");
+
+/// What to fill into the template of a new day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayScaffold {
+    pub day: u8,
+    /// The type the input is parsed into.
+    pub data: String,
+    /// The expected answer of the example for the first part, if known.
+    pub one: Option<String>,
+    /// The expected answer of the example for the second part, if known.
+    pub two: Option<String>,
+}
+
+impl DayScaffold {
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            data: "Vec<u64>".to_owned(),
+            one: None,
+            two: None,
+        }
+    }
+}
+
+/// The Rust expression of an expected answer, e.g. `Some(Answer::Number(142))`.
+/// Negative numbers are cast to `u64` like the days do with signed results.
+fn answer_expression(answer: &Option<String>) -> String {
+    match answer {
+        None => "None".to_owned(),
+        Some(answer) => match (answer.parse::<u64>(), answer.parse::<i64>()) {
+            (Ok(number), _) => format!("Some(Answer::Number({}))", number),
+            (_, Ok(number)) => format!("Some(Answer::Number({}_i64 as u64))", number),
+            _ => format!("Some(Answer::String({:?}.to_owned()))", answer),
+        },
+    }
+}
+
+/// Fills in the template of a day. The placeholders are `{{day}}`, `{{day_padded}}`,
+/// `{{data}}`, `{{one}}` and `{{two}}`, the latter being the expected answers of the example.
+pub fn render_day(template: &str, scaffold: &DayScaffold) -> String {
+    template
+        .replace("{{day}}", &scaffold.day.to_string())
+        .replace("{{day_padded}}", &format!("{:02}", scaffold.day))
+        .replace("{{data}}", &scaffold.data)
+        .replace("{{one}}", &answer_expression(&scaffold.one))
+        .replace("{{two}}", &answer_expression(&scaffold.two))
+}
+
+/// Whether the source of a day is still a stub, i.e. not marked as implemented.
+/// Only stubs may be replaced by a generated day.
+pub fn is_stub(source: &str) -> bool {
+    source.contains("const IMPLEMENTED: bool = false;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_the_stubs() {
+        let rendered = render_day(DAY_TEMPLATE, &DayScaffold::new(19));
+
+        assert_eq!(rendered, include_str!("test_fixtures/day19.rs.expected"));
+        assert!(is_stub(&rendered));
+        assert!(!is_stub(&rendered.replace(
+            "IMPLEMENTED: bool = false",
+            "IMPLEMENTED: bool = true"
+        )));
+    }
+
+    #[test]
+    fn fills_in_the_example() {
+        let rendered = render_day(
+            DAY_TEMPLATE,
+            &DayScaffold {
+                day: 5,
+                data: "Vec<i64>".to_owned(),
+                one: Some("142".to_owned()),
+                two: Some("CMZ".to_owned()),
+            },
+        );
+
+        assert!(rendered.contains("const CURRENT_DAY: u8 = 5;"));
+        assert!(rendered.contains("type Data = Vec<i64>;"));
+        assert!(rendered.contains("include_str!(\"test_inputs/test05.txt\")"));
+        assert!(rendered.contains("one: Some(Answer::Number(142)),"));
+        assert!(rendered.contains("two: Some(Answer::String(\"CMZ\".to_owned())),"));
+    }
+
+    #[test]
+    fn casts_negative_answers() {
+        assert_eq!(
+            answer_expression(&Some("-5".to_owned())),
+            "Some(Answer::Number(-5_i64 as u64))"
+        );
+        assert_eq!(
+            answer_expression(&Some("18446744073709551615".to_owned())),
+            "Some(Answer::Number(18446744073709551615))"
+        );
+        assert_eq!(
+            answer_expression(&Some("-".to_owned())),
+            "Some(Answer::String(\"-\".to_owned()))"
+        );
+        assert_eq!(answer_expression(&None), "None");
+    }
+}
@@ -0,0 +1,123 @@
+use crate::cache::write_atomic;
+use crate::config::user_config_dir;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The environment variable which overrides where the session is stored.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+
+/// Where the session is stored unless `AOC_SESSION_FILE` or `session_file` in
+/// aoc23.toml is set: `aoc23/session` in `$XDG_CONFIG_HOME` (`~/.config` if unset,
+/// `%APPDATA%` on Windows).
+pub fn default_session_path() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("session"))
+}
+
+/// Checks that only the owner can read the session file.
+#[cfg(unix)]
+fn check_permissions(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = fs::metadata(path)?.permissions().mode();
+    if mode & 0o077 != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "{} can be accessed by other users (mode {:o}), restrict it using `chmod 600 {}`",
+                path.display(),
+                mode & 0o777,
+                path.display()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// Creates the session file so that only its owner can access it,
+/// before anything is written to it.
+#[cfg(unix)]
+fn restrict_to_owner(options: &mut fs::OpenOptions) {
+    std::os::unix::fs::OpenOptionsExt::mode(options, 0o600);
+}
+
+#[cfg(not(unix))]
+fn restrict_to_owner(_options: &mut fs::OpenOptions) {}
+
+/// Loads the stored session. A missing file results in `None`, and a file
+/// which other users can access results in an error of kind `PermissionDenied`.
+pub fn load_session(path: &Path) -> io::Result<Option<String>> {
+    let session = match fs::read_to_string(path) {
+        Ok(session) => session,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    check_permissions(path)?;
+
+    let session = session.trim();
+    Ok((!session.is_empty()).then(|| session.to_owned()))
+}
+
+/// Stores the session in a file only the owner can access.
+pub fn save_session(path: &Path, session: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    write_atomic(
+        path,
+        &format!("{}\n", session.trim()),
+        Some(restrict_to_owner),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::temp_dir;
+
+    #[test]
+    fn stores_sessions() {
+        let dir = temp_dir("session-roundtrip");
+        let path = dir.join("aoc23").join("session");
+
+        assert_eq!(load_session(&path).unwrap(), None);
+        save_session(&path, "53616c746564\n").unwrap();
+        assert_eq!(
+            load_session(&path).unwrap(),
+            Some("53616c746564".to_owned())
+        );
+        save_session(&path, "6e6577").unwrap();
+        assert_eq!(load_session(&path).unwrap(), Some("6e6577".to_owned()));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn refuses_readable_sessions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = temp_dir("session-permissions");
+        let path = dir.join("session");
+        save_session(&path, "53616c746564").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        assert_eq!(
+            load_session(&path).unwrap_err().kind(),
+            io::ErrorKind::PermissionDenied
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
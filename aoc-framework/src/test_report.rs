@@ -0,0 +1,178 @@
+use crate::day::{CaseResult, TestStatus};
+use crate::{dynamic_range_time_format, PartRecord};
+use colored::*;
+
+/// The results of testing the requested parts of a day against all of its test cases.
+#[derive(Debug, Clone)]
+pub struct TestReport {
+    pub day: u8,
+    pub results: Vec<CaseResult>,
+}
+
+impl TestReport {
+    /// The combined status of a part: failed if any test case failed,
+    /// skipped if every test case was skipped (or none were run), passed otherwise.
+    pub fn part_status(&self, part: u8) -> TestStatus {
+        let statuses: Vec<TestStatus> = self
+            .results
+            .iter()
+            .filter(|r| r.part == part)
+            .map(CaseResult::status)
+            .collect();
+
+        if statuses.contains(&TestStatus::Failed) {
+            TestStatus::Failed
+        } else if statuses.contains(&TestStatus::Passed) {
+            TestStatus::Passed
+        } else {
+            TestStatus::Skipped
+        }
+    }
+
+    /// Whether none of the tested parts failed.
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(CaseResult::passed)
+    }
+
+    /// Prints the results of every test case.
+    pub fn print(&self) {
+        println!("{} Day {}", "Testing".green().bold(), self.day);
+        println!("{}", "-----------------------".green().bold());
+        println!("{}:", "Results".green().bold());
+
+        for result in &self.results {
+            println!(
+                "\t{} ({}): {}",
+                format!("Part {}", result.part).green(),
+                result.case,
+                match result.status() {
+                    TestStatus::Passed => "PASSED".green().bold(),
+                    TestStatus::Failed => "FAILED".red().bold(),
+                    TestStatus::Skipped => "SKIPPED".yellow().bold(),
+                }
+            );
+            match &result.result {
+                Some(Ok(answer)) => {
+                    println!("\t\tResult:   {}", format!("{}", answer).bold().blue())
+                }
+                Some(Err(err)) => println!("\t\tError:    {}", format!("{}", err).red()),
+                None => println!("\t\tNo known answer to test against"),
+            }
+            if let Some(expected) = &result.expected {
+                println!("\t\tExpected: {}", format!("{}", expected).bold().blue());
+            }
+            if result.result.is_some() {
+                println!(
+                    "\t\tTook:     {}",
+                    if cfg!(debug_assertions) {
+                        (dynamic_range_time_format(&result.duration) + " (DEBUG)")
+                            .bold()
+                            .red()
+                    } else {
+                        dynamic_range_time_format(&result.duration).bold().blue()
+                    }
+                );
+            }
+        }
+    }
+
+    /// One record per test case and part, for the machine readable output formats.
+    pub fn records(&self) -> Vec<PartRecord> {
+        self.results
+            .iter()
+            .map(|result| {
+                let mut record = PartRecord::new(self.day, result.part);
+                record.case = Some(result.case.to_owned());
+                record.expected = result.expected.clone();
+                record.passed = match result.status() {
+                    TestStatus::Skipped => None,
+                    status => Some(status == TestStatus::Passed),
+                };
+                match &result.result {
+                    Some(Ok(answer)) => record.answer = Some(answer.clone()),
+                    Some(Err(err)) => record.error = Some(format!("{}", err)),
+                    None => {}
+                }
+                // Parsing isn't timed separately, so the duration covers both.
+                if result.result.is_some() {
+                    record.part_time_ns = Some(result.duration.as_nanos() as u64);
+                }
+                record
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, SolveError};
+    use std::time::Duration;
+
+    fn case(
+        part: u8,
+        expected: Option<u64>,
+        result: Option<Result<u64, SolveError>>,
+    ) -> CaseResult {
+        CaseResult {
+            case: "example",
+            part,
+            expected: expected.map(Answer::Number),
+            result: result.map(|v| v.map(Answer::Number)),
+            duration: Duration::from_micros(3),
+        }
+    }
+
+    #[test]
+    fn combines_the_statuses_of_a_part() {
+        let report = TestReport {
+            day: 1,
+            results: vec![
+                case(1, Some(3), Some(Ok(3))),
+                case(1, None, None),
+                case(2, Some(4), Some(Ok(3))),
+                case(2, Some(4), Some(Ok(4))),
+            ],
+        };
+
+        assert_eq!(report.part_status(1), TestStatus::Passed);
+        assert_eq!(report.part_status(2), TestStatus::Failed);
+        assert!(!report.passed());
+
+        let report = TestReport {
+            day: 1,
+            results: vec![case(1, Some(3), Some(Ok(3))), case(2, None, None)],
+        };
+
+        assert_eq!(report.part_status(2), TestStatus::Skipped);
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn records_every_case() {
+        let report = TestReport {
+            day: 7,
+            results: vec![
+                case(1, Some(3), Some(Ok(3))),
+                case(1, Some(4), Some(Err(SolveError::parse("no cards")))),
+                case(2, None, None),
+            ],
+        };
+        let records = report.records();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].case.as_deref(), Some("example"));
+        assert_eq!(records[0].answer, Some(Answer::Number(3)));
+        assert_eq!(records[0].passed, Some(true));
+        assert_eq!(records[0].part_time_ns, Some(3000));
+        assert_eq!(records[1].expected, Some(Answer::Number(4)));
+        assert_eq!(records[1].passed, Some(false));
+        assert!(records[1].error.as_ref().unwrap().contains("no cards"));
+        assert_eq!(records[1].part_time_ns, Some(3000));
+        assert_eq!(records[2].passed, None);
+        assert_eq!(records[2].part_time_ns, None);
+        assert!(records
+            .iter()
+            .all(|v| v.day == 7 && v.parse_time_ns.is_none()));
+    }
+}
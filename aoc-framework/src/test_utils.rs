@@ -0,0 +1,10 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// An empty temporary directory for a test, which isn't created yet.
+/// The name keeps concurrently running tests apart.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc23-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
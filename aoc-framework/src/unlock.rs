@@ -0,0 +1,108 @@
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Provides the current time and a way to wait, so that waiting can be tested without really waiting.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The real clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// When a puzzle unlocks: at midnight EST (UTC-5) on the day of December.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_from_civil(year as i64, 12, day as i64);
+    UNIX_EPOCH + Duration::from_secs(days as u64 * 86400 + 5 * 3600)
+}
+
+/// Waits until the puzzle unlocks, calling `on_tick` with the remaining time about once a second.
+/// Returns right away if it's already unlocked.
+pub fn wait_for_unlock<C, F>(clock: &C, year: u16, day: u8, mut on_tick: F)
+where
+    C: Clock,
+    F: FnMut(Duration),
+{
+    let unlock = unlock_time(year, day);
+
+    while let Ok(remaining) = unlock.duration_since(clock.now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        on_tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct FakeClock(Cell<SystemTime>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.0.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        // 2023-12-01T05:00:00Z
+        assert_eq!(
+            unlock_time(2023, 1),
+            UNIX_EPOCH + Duration::from_secs(1701406800)
+        );
+        // 2020-12-25T05:00:00Z
+        assert_eq!(
+            unlock_time(2020, 25),
+            UNIX_EPOCH + Duration::from_secs(1608872400)
+        );
+    }
+
+    #[test]
+    fn waits_until_unlocked() {
+        let unlock = unlock_time(2023, 5);
+        let clock = FakeClock(Cell::new(unlock - Duration::from_millis(2500)));
+
+        let mut ticks = vec![];
+        wait_for_unlock(&clock, 2023, 5, |remaining| ticks.push(remaining));
+
+        assert_eq!(clock.now(), unlock);
+        assert_eq!(ticks, [2500, 1500, 500].map(Duration::from_millis).to_vec());
+    }
+
+    #[test]
+    fn does_not_wait_once_unlocked() {
+        let clock = FakeClock(Cell::new(unlock_time(2023, 5) + Duration::from_secs(60)));
+
+        wait_for_unlock(&clock, 2023, 5, |_| panic!("waited for an unlocked puzzle"));
+    }
+}
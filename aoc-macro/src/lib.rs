@@ -1,107 +1,135 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, punctuated::Punctuated, Ident, Token};
 
 // Again, huge thanks to andi_makes
 // this is basically a copy of his macros, with very slight modifications.
 
+/// Declares the modules of the given days, e.g. `days!(d01, d02)`, and collects
+/// them into `static DAYS`, a registry of `&dyn DynDay` ordered as listed, for
+/// the event's `Event`. The number of each day is taken from its module's name.
 #[proc_macro]
-pub fn mod_days(_input: TokenStream) -> TokenStream {
-    let mut res = String::new();
-    for i in 1..26 {
-        res += &format!("mod d{:02};", i);
-    }
-    res.parse().unwrap()
-}
+pub fn days(input: TokenStream) -> TokenStream {
+    let modules = parse_macro_input!(input with Punctuated::<Ident, Token![,]>::parse_terminated)
+        .into_iter()
+        .collect::<Vec<Ident>>();
 
-/*#[proc_macro]
-pub fn match_and_run_day(_input: TokenStream) -> TokenStream {
-    let mut res = "match day {".to_string();
-    for i in 1..26 {
-        res += &format!("{} => Day::<{}>::run_timed(input),", i, i);
+    let mut numbers = vec![];
+    for module in &modules {
+        match module
+            .to_string()
+            .strip_prefix('d')
+            .and_then(|v| v.parse::<u8>().ok())
+            .filter(|v| (1..=25).contains(v))
+        {
+            Some(number) => numbers.push(number),
+            None => {
+                return syn::Error::new(module.span(), "expected a day module like `d01`")
+                    .to_compile_error()
+                    .into()
+            }
+        }
     }
-    res += "_ => panic!(\"Days out of Bounds! No presents for you!\")};";
-    res.parse().unwrap()
-}*/
+    let count = modules.len();
 
-#[proc_macro]
-pub fn match_and_run_day_both(_input: TokenStream) -> TokenStream {
-    let r = 1_u8..26; // == [1,25]
     let res = quote! {
-        match day {
-            #(#r => {
-                Day::<#r>::run_timed(input.trim_end())
-            })*
-            _ => panic!("Days out of Bounds! No presents for you!"),
-        }
-    };
-    res.into()
-}
+        #(mod #modules;)*
 
-#[proc_macro]
-pub fn match_and_run_day_one(_input: TokenStream) -> TokenStream {
-    let r = 1_u8..26; // == [1,25]
-    let res = quote! {
-        match day {
-            #(#r => {
-                Day::<#r>::run_one_timed(input.trim_end())
-            })*
-            _ => panic!("Days out of Bounds! No presents for you!"),
-        }
+        static DAYS: [&dyn DynDay; #count] = [#(&Registered::<Day<#numbers>, _>::new(#numbers)),*];
     };
     res.into()
 }
 
+/// Generates a test module with one test per day and part, each running all of
+/// the part's test cases, and one per day comparing the answers for the cached
+/// input with the recorded ones, if there are any. Takes a list of tests to mark
+/// as `#[ignore]`, either whole days (`day19`) or single parts (`day18_part2`).
 #[proc_macro]
-pub fn match_and_run_day_two(_input: TokenStream) -> TokenStream {
-    let r = 1_u8..26; // == [1,25]
-    let res = quote! {
-        match day {
-            #(#r => {
-                Day::<#r>::run_two_timed(input.trim_end())
-            })*
-            _ => panic!("Days out of Bounds! No presents for you!"),
-        }
-    };
-    res.into()
-}
+pub fn day_tests(input: TokenStream) -> TokenStream {
+    let ignored = parse_macro_input!(input with Punctuated::<Ident, Token![,]>::parse_terminated)
+        .into_iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<String>>();
 
-#[proc_macro]
-pub fn match_and_test_day_both(_input: TokenStream) -> TokenStream {
-    let r = 1_u8..26; // == [1,25]
-    let res = quote! {
-        match day {
-            #(#r => {
-                Day::<#r>::test()
-            })*
-            _ => panic!("Days out of Bounds! No presents for you!"),
+    let mut tests = vec![];
+    let mut ignored_days = vec![];
+    for day in 1_u8..26 {
+        let day_name = format!("day{:02}", day);
+        if ignored.contains(&day_name) {
+            ignored_days.push(day);
         }
-    };
-    res.into()
-}
 
-#[proc_macro]
-pub fn match_and_test_day_one(_input: TokenStream) -> TokenStream {
-    let r = 1_u8..26; // == [1,25]
-    let res = quote! {
-        match day {
-            #(#r => {
-                Day::<#r>::test_one()
-            })*
-            _ => panic!("Days out of Bounds! No presents for you!"),
+        for (part, method) in [(1, quote!(test_one)), (2, quote!(test_two))] {
+            let name = format!("{}_part{}", day_name, part);
+            let ignore = if ignored.contains(&day_name) || ignored.contains(&name) {
+                quote!(#[ignore])
+            } else {
+                quote!()
+            };
+            let name = format_ident!("{}", name);
+
+            tests.push(quote! {
+                #[test]
+                #ignore
+                fn #name() {
+                    let failed: Vec<String> = Day::<#day>::#method()
+                        .into_iter()
+                        .filter(|r| !r.passed())
+                        .map(|r| format!("{}: expected {:?}, got {:?}", r.case, r.expected, r.result))
+                        .collect();
+                    assert!(failed.is_empty(), "{}", failed.join("\n"));
+                }
+            });
         }
-    };
-    res.into()
-}
 
-#[proc_macro]
-pub fn match_and_test_day_two(_input: TokenStream) -> TokenStream {
-    let r = 1_u8..26; // == [1,25]
+        let ignore = if ignored.contains(&day_name) {
+            quote!(#[ignore])
+        } else {
+            quote!()
+        };
+        let name = format_ident!("{}_real_input", day_name);
+        tests.push(quote! {
+            #[test]
+            #ignore
+            fn #name() {
+                // Use the cache the binary would, as configured in the aoc23.toml files.
+                let files = crate::load_config_files()
+                    .unwrap_or_else(|(path, err)| panic!("Couldn't read {}: {}", path.display(), err));
+                let config = crate::Config::resolve(&Default::default(), |var| std::env::var(var).ok(), &files.files)
+                    .unwrap();
+                let cache = crate::Cache::new(config.cache_dir.value).for_year(EVENT.year);
+                if let Some(report) = crate::verify_cached_day(&cache, EVENT.get_day(#day).unwrap()) {
+                    let failed: Vec<String> = report
+                        .results
+                        .into_iter()
+                        .filter(|r| !r.passed())
+                        .map(|r| format!("part {}: expected {:?}, got {:?}", r.part, r.expected, r.result))
+                        .collect();
+                    assert!(failed.is_empty(), "{}", failed.join("\n"));
+                }
+            }
+        });
+    }
+
     let res = quote! {
-        match day {
-            #(#r => {
-                Day::<#r>::test_two()
-            })*
-            _ => panic!("Days out of Bounds! No presents for you!"),
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #(#tests)*
+
+            #[test]
+            fn ignored_days_are_not_implemented() {
+                #(assert!(!Day::<#ignored_days>::IMPLEMENTED, "day {} is implemented, but its tests are ignored", #ignored_days);)*
+            }
+
+            #[test]
+            fn registry_contains_every_day() {
+                let days: Vec<u8> = EVENT.days().iter().map(|d| d.day()).collect();
+                assert_eq!(days, (1..=25).collect::<Vec<u8>>());
+                #(assert!(!EVENT.is_day_implemented(#ignored_days));)*
+                assert!(EVENT.get_day(0).is_none());
+            }
         }
     };
     res.into()
@@ -0,0 +1,577 @@
+//! The command line interface.
+use aoc23::{Color, ConfigFile, Event, OutputFormat, Part, Verbosity};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc23",
+    author = "LeMoonStar <webmaster@unitcore.de>",
+    version,
+    about = "My Advent Of Code 2023 solutions.",
+    subcommand_required = true,
+    arg_required_else_help = true
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub global: GlobalArgs,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Options which can be given before or after the command.
+#[derive(Debug, Args)]
+pub struct GlobalArgs {
+    /// Specifies the part of the day to compute. Overrides part in aoc23.toml. Defaults to b.
+    #[arg(short, long, value_enum, global = true)]
+    pub part: Option<Part>,
+    /// Specifies the format results are printed in. json and csv print one record per part. Overrides output in aoc23.toml. Defaults to text.
+    #[arg(short, long, value_enum, global = true)]
+    pub output: Option<OutputFormat>,
+    /// The server to download inputs from and submit answers to. Overrides the AOC_BASE_URL environment variable and base_url in aoc23.toml. Defaults to https://adventofcode.com.
+    #[arg(long, global = true, value_parser = parse_base_url)]
+    pub base_url: Option<String>,
+    /// The event to download inputs from and submit answers to. Overrides the AOC_YEAR environment variable and year in aoc23.toml. Defaults to 2023.
+    #[arg(long, global = true)]
+    pub year: Option<u16>,
+    /// The user agent sent to the server. Overrides the AOC_USER_AGENT environment variable and user_agent in aoc23.toml.
+    #[arg(long, global = true)]
+    pub user_agent: Option<String>,
+    /// The directory inputs, answers and submissions are stored in. Overrides the AOC_CACHE_DIR environment variable and cache_dir in aoc23.toml. Defaults to ./.aoc23_cache if it exists, otherwise to aoc23 in the user's cache directory.
+    #[arg(long, global = true)]
+    pub cache_dir: Option<PathBuf>,
+    /// Whether to color the output. Overrides color in aoc23.toml. Defaults to auto.
+    #[arg(long, value_enum, value_name = "WHEN", global = true)]
+    pub color: Option<Color>,
+    /// How much information the solutions print. Overrides verbosity in aoc23.toml. Defaults to none.
+    #[arg(long, value_enum, global = true, conflicts_with_all = ["verbose", "development"])]
+    pub verbosity: Option<Verbosity>,
+    /// Print verbose information, the same as --verbosity verbose.
+    #[arg(short, long, global = true, conflicts_with = "development")]
+    pub verbose: bool,
+    /// Print development information, the same as --verbosity dev.
+    #[arg(short, long = "dev", global = true)]
+    pub development: bool,
+}
+
+impl Cli {
+    /// The settings given on the command line, which take precedence over the configuration.
+    pub fn overrides(&self) -> ConfigFile {
+        ConfigFile {
+            base_url: self.global.base_url.clone(),
+            year: self.global.year,
+            user_agent: self.global.user_agent.clone(),
+            cache_dir: self.global.cache_dir.clone(),
+            part: self.global.part.clone(),
+            output: self.global.output.clone(),
+            color: self.global.color,
+            verbosity: self.global.verbosity(),
+            bench_iterations: match &self.command {
+                Command::Bench { iterations, .. } => *iterations,
+                _ => None,
+            },
+            ..ConfigFile::default()
+        }
+    }
+}
+
+impl GlobalArgs {
+    /// The verbosity selected by either --verbosity or one of its flags.
+    pub fn verbosity(&self) -> Option<Verbosity> {
+        if self.verbose {
+            Some(Verbosity::Verbose)
+        } else if self.development {
+            Some(Verbosity::Development)
+        } else {
+            self.verbosity.clone()
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Use either a file or stdin as input and run the solution.
+    Run {
+        /// The number of the day.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// Specify a file to be used as input, otherwise use stdin.
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+    },
+    /// Automatically download input from AoC using the provided session and run the solution.
+    Auto {
+        /// A day, a range of days like 1-10, or "all". Ranges and "all" only include implemented days.
+        #[arg(value_parser = parse_day_selection)]
+        days: DaySelection,
+        #[command(flatten)]
+        session: SessionArg,
+        /// Don't cache the input, and delete any current cache for this day.
+        #[arg(short = 'N', long)]
+        no_cache: bool,
+        /// If the day isn't unlocked yet, wait for it with a countdown instead of failing.
+        #[arg(short, long)]
+        wait: bool,
+    },
+    /// Run the solution repeatedly and print statistics on how long each step took.
+    Bench {
+        /// A day, a range of days like 1-10, or "all". Ranges and "all" only include implemented days.
+        #[arg(value_parser = parse_day_selection)]
+        days: DaySelection,
+        /// Specify a file to be used as input, otherwise use the cached or downloaded input like the auto command.
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+        #[command(flatten)]
+        session: SessionArg,
+        /// The maximum amount of measured runs. Overrides bench_iterations in aoc23.toml.
+        #[arg(short = 'n', long)]
+        iterations: Option<usize>,
+        /// Stop measuring after this many seconds. Defaults to 3 if --iterations isn't given.
+        #[arg(short, long, value_parser = parse_seconds)]
+        time: Option<Duration>,
+        /// The amount of runs before measuring.
+        #[arg(short, long, default_value_t = 3)]
+        warmup: usize,
+        /// Store the results as the new baseline in the cache.
+        #[arg(short = 'S', long)]
+        save_baseline: bool,
+        /// Compare the median of each step with the baseline, and fail if any regressed beyond the threshold.
+        #[arg(short, long)]
+        compare: bool,
+        /// How many percent slower than the baseline a step may get before counting as a regression.
+        #[arg(long, default_value_t = 10.0, value_parser = parse_threshold)]
+        threshold: f64,
+    },
+    /// Test the day with the example input data.
+    Test {
+        /// A day, a range of days like 1-10, or "all". Ranges and "all" only include implemented days.
+        #[arg(value_parser = parse_day_selection)]
+        days: DaySelection,
+    },
+    /// Compute the answer for the part selected using --part and submit it.
+    Submit {
+        /// The number of the day.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        #[command(flatten)]
+        session: SessionArg,
+    },
+    /// Run the solution on the real input and compare the answers with the recorded ones.
+    Verify {
+        /// A day, a range of days like 1-10, or "all". Ranges and "all" only include implemented days.
+        #[arg(value_parser = parse_day_selection)]
+        days: DaySelection,
+        #[command(flatten)]
+        session: SessionArg,
+        /// Record the current answers in the cache instead of comparing. Only use this once they have been accepted.
+        #[arg(short, long)]
+        record: bool,
+    },
+    /// Download and print the puzzle description, and store its example blocks as test input candidates. The second part is only included with a session.
+    FetchPuzzle {
+        /// The number of the day.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        #[command(flatten)]
+        session: SessionArg,
+        /// The directory to store the examples in, as testNN_exampleK.txt. The first one is also stored as testNN.txt. Files which aren't empty are never overwritten. Defaults to src/yYYYY/test_inputs.
+        #[arg(long)]
+        examples_dir: Option<PathBuf>,
+    },
+    /// Generate the module of a day from a template. Only days which are still stubs are replaced.
+    NewDay {
+        /// The number of the day.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// The type the input is parsed into. The default template parses it line by line.
+        #[arg(long, default_value = "Vec<u64>")]
+        data: String,
+        /// The expected answer of the example for part 1.
+        #[arg(long)]
+        one: Option<String>,
+        /// The expected answer of the example for part 2.
+        #[arg(long)]
+        two: Option<String>,
+        /// A file containing the example input, which is copied to test_inputs/testNN.txt.
+        #[arg(long)]
+        example: Option<PathBuf>,
+        /// Take the example and its answers from the puzzle downloaded by the fetch-puzzle command.
+        #[arg(long)]
+        from_puzzle: bool,
+        /// The template to use. Overrides day_template in aoc23.toml. See aoc-framework/src/templates/day.rs.template for the placeholders.
+        #[arg(long)]
+        template: Option<PathBuf>,
+        /// The directory the days are stored in. Defaults to src/yYYYY, e.g. src/y2023.
+        #[arg(long)]
+        days_dir: Option<PathBuf>,
+    },
+    /// Print the calendar with the title, tags and stars of each day. Lists all days unless a day or range is given.
+    List {
+        /// A day, a range of days like 1-10, or "all".
+        #[arg(value_parser = parse_any_day_selection)]
+        days: Option<DaySelection>,
+        /// Only list days with this tag, e.g. grid or pathfinding.
+        #[arg(long)]
+        tag: Option<String>,
+    },
+    /// Check a session and store it, so that other commands use it automatically. Unless it's provided using --session or AOC_SESSION, the session is read from stdin.
+    Login {
+        /// The AoC browser session string.
+        #[arg(short, long)]
+        session: Option<String>,
+    },
+    /// Manage the cached inputs of the selected days. Unlike other commands, ranges and "all" include days which aren't implemented.
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Inspect the configuration.
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// Print a script which completes the arguments of aoc23 in a shell.
+    Completions {
+        /// The shell to complete arguments in.
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+    /// Print the man page of aoc23, or write the pages of all commands to a directory.
+    Man {
+        /// The directory to write a page for each command to, instead of printing the main page.
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CacheCommand {
+    /// List the cached inputs and whether they are valid.
+    List {
+        /// A day, a range of days like 1-10, or "all".
+        #[arg(value_parser = parse_any_day_selection)]
+        days: DaySelection,
+    },
+    /// Print the cached input of a day.
+    Show {
+        /// The number of the day.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+    },
+    /// Download the inputs of all unlocked days which aren't cached yet.
+    Prefetch {
+        /// A day, a range of days like 1-10, or "all".
+        #[arg(value_parser = parse_any_day_selection)]
+        days: DaySelection,
+        #[command(flatten)]
+        session: SessionArg,
+    },
+    /// Use a file as the input of a day.
+    Import {
+        /// The number of the day.
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// The file containing the input.
+        file: PathBuf,
+    },
+    /// Delete the cached inputs and puzzle descriptions. Recorded answers and submissions are kept.
+    Purge {
+        /// A day, a range of days like 1-10, or "all".
+        #[arg(value_parser = parse_any_day_selection)]
+        days: DaySelection,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Print the effective configuration, merged from the command line, the environment, ./aoc23.toml and the user's aoc23.toml, and where each setting came from.
+    Show,
+}
+
+#[derive(Debug, Args)]
+pub struct SessionArg {
+    /// The AoC browser session string. If not provided, uses the AOC_SESSION environment variable or the session stored by the login command.
+    #[arg(short, long)]
+    pub session: Option<String>,
+}
+
+/// The days selected by a day, an inclusive range like `1-10`, or `all`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaySelection {
+    days: Vec<u8>,
+    single: bool,
+}
+
+impl DaySelection {
+    /// All days of the selection, whether they are implemented or not.
+    pub fn all(&self) -> Vec<u8> {
+        self.days.clone()
+    }
+
+    /// The selected days which can be run. A single day is always kept, so
+    /// that running it reports that it isn't implemented, while ranges and
+    /// `all` only contain days which have already been implemented.
+    pub fn implemented(&self, solutions: Option<&Event>) -> Result<Vec<u8>, String> {
+        if self.single {
+            return Ok(self.days.clone());
+        }
+
+        let days: Vec<u8> = self
+            .days
+            .iter()
+            .copied()
+            .filter(|day| solutions.is_some_and(|v| v.is_day_implemented(*day)))
+            .collect();
+        if days.is_empty() {
+            return Err("There are no implemented days in this range.".to_string());
+        }
+        Ok(days)
+    }
+}
+
+/// Parses a single day.
+fn parse_day(v: &str) -> Result<u8, String> {
+    match v.trim().parse::<u8>() {
+        Ok(day) if 0 < day && day <= 25 => Ok(day),
+        Ok(_) => Err("The day must be between 1 and 25.".to_string()),
+        Err(_) => Err("The day must be a number between 1 and 25.".to_string()),
+    }
+}
+
+/// Parses a day, a range of days or "all". Ranges and "all" only include implemented days.
+fn parse_day_selection(v: &str) -> Result<DaySelection, String> {
+    parse_any_day_selection(v).map_err(|_| {
+        "The day must be a number between 1 and 25, a range like 1-10, or \"all\". Ranges and \"all\" only include implemented days.".to_string()
+    })
+}
+
+/// Parses a day, a range of days or "all", including days which aren't implemented.
+fn parse_any_day_selection(v: &str) -> Result<DaySelection, String> {
+    if v == "all" {
+        Ok(DaySelection {
+            days: (1..=25).collect(),
+            single: false,
+        })
+    } else if let Some((start, end)) = v.split_once('-') {
+        let (start, end) = (parse_day(start)?, parse_day(end)?);
+        if start > end {
+            return Err("The start of a range must not be after its end.".to_string());
+        }
+        Ok(DaySelection {
+            days: (start..=end).collect(),
+            single: false,
+        })
+    } else {
+        Ok(DaySelection {
+            days: vec![parse_day(v)?],
+            single: true,
+        })
+    }
+}
+
+fn parse_base_url(v: &str) -> Result<String, String> {
+    v.parse::<reqwest::Url>()
+        .map(|_| v.to_owned())
+        .map_err(|err| format!("Invalid base URL: {}", err))
+}
+
+/// Parses a positive amount of seconds.
+fn parse_seconds(v: &str) -> Result<Duration, String> {
+    match v.trim().parse::<f64>() {
+        Ok(secs) if secs > 0.0 => Duration::try_from_secs_f64(secs)
+            .map_err(|_| "The time must be a number of seconds greater than 0.".to_string()),
+        _ => Err("The time must be a number of seconds greater than 0.".to_string()),
+    }
+}
+
+/// Parses a percentage which isn't negative.
+fn parse_threshold(v: &str) -> Result<f64, String> {
+    match v.trim().parse::<f64>() {
+        Ok(percent) if percent.is_finite() && percent >= 0.0 => Ok(percent),
+        _ => Err("The threshold must be a percentage of at least 0.".to_string()),
+    }
+}
+
+/// Parses the command line, also accepting the days in front of the command
+/// like `aoc23 5 run`, as in earlier versions.
+pub fn parse() -> Cli {
+    Cli::parse_from(move_days_behind_command(std::env::args_os().collect()))
+}
+
+/// Rewrites arguments like `aoc23 --part 1 5 submit` to `aoc23 --part 1 submit 5`,
+/// and `aoc23 5 cache import input.txt` to `aoc23 cache import 5 input.txt`.
+/// Other arguments are returned unchanged.
+fn move_days_behind_command(mut args: Vec<OsString>) -> Vec<OsString> {
+    let command = Cli::command();
+    // The options which take a value, since their values aren't positionals.
+    let takes_value: Vec<String> = command
+        .get_arguments()
+        .filter(|arg| arg.get_action().takes_values())
+        .flat_map(|arg| {
+            let long = arg.get_long().map(|v| format!("--{}", v));
+            let short = arg.get_short().map(|v| format!("-{}", v));
+            long.into_iter().chain(short)
+        })
+        .collect();
+
+    let mut positionals = vec![];
+    let mut i = 1;
+    while i < args.len() && positionals.len() < 3 {
+        let arg = args[i].to_string_lossy();
+        if arg == "--" {
+            break;
+        } else if arg.starts_with('-') && arg.len() > 1 {
+            if takes_value.iter().any(|v| *v == arg) {
+                i += 1;
+            }
+        } else {
+            positionals.push(i);
+        }
+        i += 1;
+    }
+
+    let positional = |i: usize| positionals.get(i).map(|v| args[*v].to_string_lossy());
+    let is_command = |name: &str| command.get_subcommands().any(|v| v.get_name() == name);
+    let (Some(days), Some(name)) = (positional(0), positional(1)) else {
+        return args;
+    };
+    if parse_any_day_selection(&days).is_err() || !is_command(&name) {
+        return args;
+    }
+
+    // The days are the first positional of the command, or of the subcommand of `cache`.
+    let position = match (name.as_ref(), positionals.get(2)) {
+        ("cache", Some(subcommand)) => *subcommand,
+        _ => positionals[1],
+    };
+    let days = args.remove(positionals[0]);
+    args.insert(position, days);
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(v: &str) -> Vec<OsString> {
+        v.split_whitespace().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn cli_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn moves_days_behind_command() {
+        let cases = [
+            ("aoc23 5 run", "aoc23 run 5"),
+            ("aoc23 5 run -f input.txt", "aoc23 run 5 -f input.txt"),
+            ("aoc23 --part 1 5 submit", "aoc23 --part 1 submit 5"),
+            ("aoc23 -p 2 -d 1-3 test", "aoc23 -p 2 -d test 1-3"),
+            (
+                "aoc23 --output json all test",
+                "aoc23 --output json test all",
+            ),
+            ("aoc23 5 cache import in.txt", "aoc23 cache import 5 in.txt"),
+            ("aoc23 all cache list", "aoc23 cache list all"),
+            ("aoc23 --year=2022 3 list", "aoc23 --year=2022 list 3"),
+        ];
+        for (old, new) in cases {
+            assert_eq!(move_days_behind_command(args(old)), args(new), "{}", old);
+        }
+    }
+
+    #[test]
+    fn keeps_other_arguments() {
+        for v in [
+            "aoc23 run 5",
+            "aoc23 --part 1 submit 5",
+            "aoc23 --year 2022 list",
+            "aoc23 login",
+            "aoc23 cache import 5 in.txt",
+            "aoc23 5",
+            "aoc23 --version",
+        ] {
+            assert_eq!(move_days_behind_command(args(v)), args(v), "{}", v);
+        }
+    }
+
+    #[test]
+    fn parses_both_forms() {
+        for v in [
+            "aoc23 -p 1 5 submit -s abc",
+            "aoc23 submit 5 -s abc --part 1",
+        ] {
+            let cli = Cli::try_parse_from(move_days_behind_command(args(v))).unwrap();
+            assert_eq!(cli.global.part, Some(Part::One));
+            match cli.command {
+                Command::Submit { day, session } => {
+                    assert_eq!(day, 5);
+                    assert_eq!(session.session.as_deref(), Some("abc"));
+                }
+                command => panic!("unexpected command {:?}", command),
+            }
+        }
+    }
+
+    #[test]
+    fn parses_verbosity() {
+        let verbosity = |v: &str| {
+            Cli::try_parse_from(args(v))
+                .map(|cli| cli.global.verbosity())
+                .map_err(|_| ())
+        };
+        assert_eq!(verbosity("aoc23 test 1"), Ok(None));
+        assert_eq!(verbosity("aoc23 test 1 -v"), Ok(Some(Verbosity::Verbose)));
+        assert_eq!(
+            verbosity("aoc23 -d test 1"),
+            Ok(Some(Verbosity::Development))
+        );
+        assert_eq!(
+            verbosity("aoc23 --verbosity dev test 1"),
+            Ok(Some(Verbosity::Development))
+        );
+        assert_eq!(verbosity("aoc23 -v -d test 1"), Err(()));
+    }
+
+    #[test]
+    fn overrides_configuration() {
+        let cli = Cli::try_parse_from(args("aoc23 bench 3 -n 20 --color never -d")).unwrap();
+        let overrides = cli.overrides();
+        assert_eq!(overrides.bench_iterations, Some(20));
+        assert_eq!(overrides.color, Some(Color::Never));
+        assert_eq!(overrides.verbosity, Some(Verbosity::Development));
+        assert_eq!(overrides.part, None);
+
+        let cli = Cli::try_parse_from(args("aoc23 test 3")).unwrap();
+        assert_eq!(cli.overrides(), ConfigFile::default());
+    }
+
+    #[test]
+    fn parses_bench_limits() {
+        assert_eq!(parse_seconds("1.5"), Ok(Duration::from_millis(1500)));
+        for v in ["0", "-1", "inf", "NaN", "1e300", "x"] {
+            assert!(parse_seconds(v).is_err(), "{}", v);
+        }
+
+        assert_eq!(parse_threshold("0"), Ok(0.0));
+        assert_eq!(parse_threshold("12.5"), Ok(12.5));
+        for v in ["-1", "inf", "NaN", "x"] {
+            assert!(parse_threshold(v).is_err(), "{}", v);
+        }
+    }
+
+    #[test]
+    fn selects_days() {
+        let selection = |v: &str| parse_day_selection(v).unwrap();
+        assert_eq!(selection("7").implemented(None), Ok(vec![7]));
+        assert_eq!(selection("3-5").all(), vec![3, 4, 5]);
+        assert!(selection("all").implemented(None).is_err());
+        assert!(parse_day_selection("0").is_err());
+        assert!(parse_day_selection("5-3").is_err());
+    }
+}
@@ -1,92 +1,89 @@
-use aoc23::{run_day, set_verbosity, test_day, Part, Verbosity};
-use clap::{App, AppSettings, Arg, SubCommand};
+use aoc23::{
+    add_submission, answer_status, bench_and_print_day, calendar, compare_with_baseline, event,
+    format_records, is_stub, load_answers, load_baseline, load_config_files, load_history,
+    load_session, migrate_legacy_cache, parse_puzzle, print_calendar, record_answers, render_day,
+    run_day, run_days, run_days_records, save_answers, save_baseline, save_history, save_session,
+    set_verbosity, solve_part, test_day, unlock_time, verify_day, wait_for_unlock, AnswerStatus,
+    AocClient, BenchSettings, Cache, CacheStatus, ClientConfig, Clock, Color, Config, ConfigFiles,
+    DayScaffold, DownloadError, DynDay, Event, History, OutputFormat, Part, PartRecord,
+    SystemClock, TestReport, TestStatus, Verbosity, Verdict, CONFIG_FILE, DAY_TEMPLATE,
+    LEGACY_CACHE_DIR, SESSION_FILE_VAR,
+};
+use clap::CommandFactory;
+use cli::{CacheCommand, Cli, Command, ConfigCommand};
 use colored::*;
-use reqwest::blocking::Client;
-use reqwest::cookie::Jar;
-use reqwest::header::USER_AGENT;
 use std::env;
 use std::fs;
-use std::path::Path;
-use std::sync::Arc;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
-// NOTE: Since this CLI was coded using clap in december of 2021, there seem to
-// have been huge breaking changes, requiring me to rewrite the CLI completely
-// to be able to update Clap. As I am on a limited time schedule RN, I will
-// probably do this later on.
-// 2023-11-29: still haven't done this, maybe I'll do is this year.
-// TODO: Rewrite CLI and update Clap
+mod cli;
+
+/// How long to wait between downloads when prefetching inputs, to not flood the server.
+const PREFETCH_DELAY: Duration = Duration::from_secs(1);
 
 fn main() {
-    let matches = App::new("Advent Of Code 2023")
-        .author("LeMoonStar <webmaster@unitcore.de>")
-        .about("My Advent Of Code 2023 solutions.")
-        .setting(AppSettings::SubcommandRequiredElseHelp)
-        .arg(
-            Arg::with_name("day")
-                .help("The number of the day to execute")
-                .required(true)
-                .takes_value(true)
-                .validator(|v| match v.parse::<u8>() {
-                    Ok(day) => {
-                        if 0 < day && day <= 25 {
-                            Ok(())
-                        } else {
-                            Err("The day must be between 1 and 25.".to_string())
-                        }
-                    }
-                    Err(_) => Err("The day must be a number between 1 and 25.".to_string()),
-                }),
-        )
-        .arg(
-            Arg::with_name("part")
-                .help("Specifies the part of the day to compute.")
-                .long("part")
-                .short("p")
-                .default_value("b")
-                .possible_values(&["1", "2", "b"])
-                .takes_value(true),
-        )
-        .arg(
-            Arg::with_name("verbose")
-                .help("Print verbose information")
-                .long("verbose")
-                .short("v")
-                .conflicts_with("development"))
-        .arg(
-            Arg::with_name("development")
-                .help("Print development information")
-                .long("dev")
-                .short("d")
-                .conflicts_with("verbose"))
-        .subcommand(
-            SubCommand::with_name("test").about("Test the day with the example input data."),
-        )
-        .subcommand(
-            SubCommand::with_name("auto")
-                .about("Automatically download input from AoC using the provided session and run the solution.")
-                .arg(Arg::with_name("session")
-                    .help("The AoC browser session string. If not provided, uses the AOC_SESSION environment variable.")
-                    .short("s")
-                    .long("session")
-                    .takes_value(true))
-                .arg(Arg::with_name("no_cache")
-                    .help("Don't cache the input, and delete any current cache for this day.")
-                    .short("N")
-                    .long("no-cache")))
-        .subcommand(
-            SubCommand::with_name("run")
-                .about("Use either a file or stdin as input and run the solution.")
-                .arg(Arg::with_name("file")
-                    .help("Specify a file to be used as input, otherwise use stdin.")
-                    .short("f")
-                    .long("file")
-                    .takes_value(true)
-            )
-        )
-        .get_matches();
+    let cli = cli::parse();
+
+    // Neither of these depend on the configuration, so they work even if it's broken.
+    match &cli.command {
+        Command::Completions { shell } => {
+            clap_complete::generate(*shell, &mut Cli::command(), "aoc23", &mut io::stdout());
+            return;
+        }
+        Command::Man { out_dir } => {
+            let written = match out_dir {
+                Some(dir) => fs::create_dir_all(dir)
+                    .and_then(|_| clap_mangen::generate_to(Cli::command(), dir)),
+                None => clap_mangen::Man::new(Cli::command()).render(&mut io::stdout()),
+            };
+            if let Err(err) = written {
+                println!(
+                    "{}",
+                    format!("Couldn't write the man page: {}", err).red().bold()
+                );
+                std::process::exit(1);
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    // The project configuration takes precedence over the user's.
+    let files = match load_config_files() {
+        Ok(ConfigFiles { files, warnings }) => {
+            for warning in warnings {
+                eprintln!("{}", format!("Warning! {}", warning).yellow());
+            }
+            files
+        }
+        Err((path, err)) => {
+            println!(
+                "{}",
+                format!("Couldn't read {}: {}", path.display(), err)
+                    .red()
+                    .bold()
+            );
+            std::process::exit(1);
+        }
+    };
+    let config = match Config::resolve(&cli.overrides(), |var| env::var(var).ok(), &files) {
+        Ok(config) => config,
+        Err(err) => {
+            println!("{}", err.red().bold());
+            std::process::exit(1);
+        }
+    };
+    match config.color.value {
+        Color::Always => colored::control::set_override(true),
+        Color::Never => colored::control::set_override(false),
+        Color::Auto => {}
+    }
 
     if cfg!(debug_assertions) {
-        println!(
+        eprintln!(
             "{}",
             "This binary was built in debug mode. To improve performance, please add --release to the build command."
                 .red()
@@ -94,76 +91,992 @@ fn main() {
         );
     }
 
-    let day = matches
-        .value_of("day")
-        .unwrap()
-        .parse::<u8>()
-        .expect("Failed to parse day argument.");
-
-    let part: Part = match matches.value_of("part") {
-        Some("1") => Part::One,
-        Some("2") => Part::Two,
-        Some("b") => Part::Both,
-        _ => panic!("unexpected part argument."),
+    let part = config.part.value.clone();
+    let output = config.output.value.clone();
+    let session_file = config.session_file.value.as_deref();
+    let cache = Cache::new(config.cache_dir.value.clone());
+    match migrate_legacy_cache(Path::new(LEGACY_CACHE_DIR), cache.dir()) {
+        Ok(true) => eprintln!(
+            "{}",
+            format!(
+                "Moved the cache from {} to {}.",
+                LEGACY_CACHE_DIR,
+                cache.dir().display()
+            )
+            .yellow()
+        ),
+        Ok(false) => {}
+        Err(err) => eprintln!(
+            "{}",
+            format!(
+                "Warning! {} isn't used anymore, and couldn't be moved to {}: {}. Move its content or delete it.",
+                LEGACY_CACHE_DIR,
+                cache.dir().display(),
+                err
+            )
+            .yellow()
+        ),
+    }
+    let client_config = config.client_config();
+    let solutions = event(client_config.year);
+    // The solutions of the selected event, for commands which run them.
+    let get_event = || -> &'static Event {
+        solutions.unwrap_or_else(|| {
+            println!(
+                "{}",
+                format!("There are no solutions for {}.", client_config.year)
+                    .red()
+                    .bold()
+            );
+            std::process::exit(1);
+        })
+    };
+    let get_day = |day: u8| -> &'static dyn DynDay {
+        get_event()
+            .get_day(day)
+            .expect("Days out of Bounds! No presents for you!")
     };
+    let cache = cache.for_year(client_config.year);
 
-    if matches.args.contains_key("verbose") {
-        #[cfg(not(debug_assertions))]
-        println!("{}", "verbosity and development prints are only available in unoptimized builds (compiled without --release).".red().bold());
-        set_verbosity(Verbosity::Verbose);
+    let days = match &cli.command {
+        Command::Auto { days, .. }
+        | Command::Bench { days, .. }
+        | Command::Test { days }
+        | Command::Verify { days, .. } => days.implemented(solutions),
+        Command::List { days, .. } => Ok(days.as_ref().map_or((1..=25).collect(), |v| v.all())),
+        // Inputs can be cached before the day is implemented.
+        Command::Cache {
+            command:
+                CacheCommand::List { days }
+                | CacheCommand::Prefetch { days, .. }
+                | CacheCommand::Purge { days },
+        } => Ok(days.all()),
+        _ => Ok(vec![]),
     }
+    .unwrap_or_else(|err| {
+        println!("{}", err.red().bold());
+        std::process::exit(1);
+    });
 
-    if matches.args.contains_key("development") {
-        #[cfg(not(debug_assertions))]
+    let verbosity = config.verbosity.value.clone();
+    if cfg!(not(debug_assertions)) && verbosity != Verbosity::None {
         println!("{}", "verbosity and development prints are only available in unoptimized builds (compiled without --release).".red().bold());
-        set_verbosity(Verbosity::Development);
     }
+    set_verbosity(verbosity);
+
+    match cli.command {
+        Command::Run { day, file } => {
+            let input = match file {
+                Some(f) => read_input_file(&f),
+                None => get_stdin_day_input(day),
+            };
+            let success = match output {
+                OutputFormat::Text => {
+                    run_day(get_day(day), part, &input, &load_submission_history(&cache))
+                }
+                _ => print_records(&run_days_records(&[(get_day(day), input)], part), &output),
+            };
+            if !success {
+                std::process::exit(1);
+            }
+        }
+        Command::Auto {
+            session,
+            no_cache,
+            wait,
+            ..
+        } => {
+            let session = get_session(session.session, session_file);
+            let use_cache = !no_cache;
 
-    match matches.subcommand() {
-        ("run", c_matches) => {
-            let input = match c_matches {
-                Some(c_matches) => {
-                    if let Some(f) = c_matches.value_of("file") {
-                        fs::read_to_string(Path::new(f)).expect("Error while reading input file")
-                    } else {
-                        get_stdin_day_input(day)
+            let inputs: Vec<(&dyn DynDay, String)> = days
+                .iter()
+                .map(|day| {
+                    if wait {
+                        wait_for_day(*day, client_config.year, &SystemClock);
                     }
+                    (
+                        get_day(*day),
+                        get_auto_input(*day, session.as_ref(), use_cache, &cache, &client_config)
+                            .unwrap_or_else(|err| exit_with_download_error(*day, err)),
+                    )
+                })
+                .collect();
+            let success = match (&output, &inputs[..]) {
+                (OutputFormat::Text, [(day, input)]) => {
+                    run_day(*day, part, input, &load_submission_history(&cache))
                 }
-                None => get_stdin_day_input(day),
+                (OutputFormat::Text, _) => {
+                    run_days(&inputs, part, &load_submission_history(&cache))
+                }
+                _ => print_records(&run_days_records(&inputs, part), &output),
             };
-            run_day(day, part, &input);
+            if !success {
+                std::process::exit(1);
+            }
         }
-        ("auto", c_matches) => {
-            let session: Option<String> = match c_matches {
-                Some(c_matches) => match c_matches.value_of("session") {
-                    Some(v) => Some(v.to_owned()),
-                    None => env::var("AOC_SESSION").ok(),
+        Command::Bench {
+            file,
+            session,
+            time,
+            warmup,
+            save_baseline: save,
+            compare,
+            threshold,
+            ..
+        } => {
+            // Given using --iterations or bench_iterations in aoc23.toml.
+            let iterations = config.bench_iterations.value;
+            let settings = BenchSettings {
+                warmup,
+                iterations,
+                time_budget: match (time, iterations) {
+                    (Some(time), _) => Some(time),
+                    (None, Some(_)) => None,
+                    (None, None) => BenchSettings::default().time_budget,
                 },
-                None => env::var("AOC_SESSION").ok(),
             };
-            let cache = if let Some(c_matches) = c_matches {
-                !c_matches.args.contains_key("no_cache")
+
+            let baseline_path = &cache.baseline_path();
+            let baseline = if compare {
+                match load_baseline(baseline_path) {
+                    Ok(baseline) => Some(baseline),
+                    Err(err) => {
+                        println!(
+                            "{}",
+                            format!("Couldn't read the benchmark baseline: {}", err)
+                                .red()
+                                .bold()
+                        );
+                        std::process::exit(1);
+                    }
+                }
             } else {
-                true
+                None
+            };
+            let mut success = true;
+            let mut results = vec![];
+            for day in &days {
+                let input = match &file {
+                    Some(f) => read_input_file(f),
+                    None => {
+                        let session = get_session(session.session.clone(), session_file);
+                        get_auto_input(*day, session.as_ref(), true, &cache, &client_config)
+                            .unwrap_or_else(|err| exit_with_download_error(*day, err))
+                    }
+                };
+                match bench_and_print_day(get_day(*day), part.clone(), &input, &settings) {
+                    Some(result) => {
+                        if let Some(baseline) = &baseline {
+                            success &= compare_with_baseline(&result, baseline, threshold);
+                        }
+                        results.push(result);
+                    }
+                    None => success = false,
+                }
+            }
+
+            if save {
+                match save_baseline(baseline_path, &results) {
+                    Ok(_) => println!("{}", "Saved the benchmark baseline.".green().bold()),
+                    Err(err) => {
+                        println!(
+                            "{}",
+                            format!("Couldn't save the benchmark baseline: {}", err)
+                                .red()
+                                .bold()
+                        );
+                        success = false;
+                    }
+                }
+            }
+            if !success {
+                std::process::exit(1);
+            }
+        }
+        Command::Submit { day, session } => {
+            let part_number = match part {
+                Part::One => 1,
+                Part::Two => 2,
+                Part::Both => {
+                    println!(
+                        "{}",
+                        "Please select the part to submit using --part."
+                            .red()
+                            .bold()
+                    );
+                    std::process::exit(1);
+                }
+            };
+            let session = match get_session(session.session, session_file) {
+                Some(session) => session,
+                None => {
+                    println!(
+                        "{}",
+                        "No session was provided using the session argument, the AOC_SESSION environment variable or the login command."
+                            .red()
+                            .bold()
+                    );
+                    std::process::exit(1);
+                }
             };
 
-            let input = get_auto_input(day, session.as_ref(), cache);
-            run_day(day, part, &input);
+            let input = get_auto_input(day, Some(&session), true, &cache, &client_config)
+                .unwrap_or_else(|err| exit_with_download_error(day, err));
+            let answer = match solve_part(get_day(day), part_number, &input) {
+                Ok(answer) => answer,
+                Err(err) => {
+                    println!("{}:", "Error".red().bold());
+                    println!("\t{}", format!("{}", err).bold().red());
+                    std::process::exit(1);
+                }
+            };
+            let submission = match answer.submission() {
+                Some(submission) => submission,
+                None => {
+                    println!("{}", answer);
+                    println!(
+                        "{}",
+                        "Bitmap answers have to be read and submitted manually."
+                            .red()
+                            .bold()
+                    );
+                    std::process::exit(1);
+                }
+            };
+
+            let history_path = &cache.history_path();
+            let mut history = load_submission_history(&cache);
+            match answer_status(&history, day, part_number, &submission) {
+                AnswerStatus::New => {}
+                AnswerStatus::Accepted => {
+                    println!(
+                        "{} {} was already accepted.",
+                        "Not submitting:".green().bold(),
+                        submission.bold().blue()
+                    );
+                    return;
+                }
+                status => {
+                    println!(
+                        "{} {} is {}.",
+                        "Not submitting:".red().bold(),
+                        submission.bold().blue(),
+                        status.to_string().red()
+                    );
+                    std::process::exit(1);
+                }
+            }
+
+            println!(
+                "{} {} for day {} part {}",
+                "Submitting".green().bold(),
+                submission.bold().blue(),
+                day,
+                part_number
+            );
+            let verdict = match AocClient::new(client_config.clone(), &session)
+                .and_then(|client| client.submit_answer(day, part_number, &submission))
+            {
+                Ok(verdict) => verdict,
+                Err(err) => {
+                    println!(
+                        "{}",
+                        format!("Error while submitting the answer: {}", err)
+                            .red()
+                            .bold()
+                    );
+                    std::process::exit(1);
+                }
+            };
+
+            add_submission(&mut history, day, part_number, &submission, verdict.clone());
+            if let Err(err) = save_history(history_path, &history) {
+                eprintln!("Warning! couldn't save the submission history: {}", err);
+            }
+
+            if verdict != Verdict::Correct {
+                println!("\t{}", verdict.to_string().red().bold());
+                std::process::exit(1);
+            }
+            println!("\t{}", verdict.to_string().green().bold());
+
+            // Accepted answers are recorded for the verify command.
+            let answers_path = &cache.answers_path();
+            let recorded = load_answers(answers_path).and_then(|mut answers| {
+                let recorded = answers.entry(day).or_default();
+                match part_number {
+                    1 => recorded.one = Some(answer),
+                    _ => recorded.two = Some(answer),
+                }
+                save_answers(answers_path, &answers)
+            });
+            if let Err(err) = recorded {
+                eprintln!("Warning! couldn't record the accepted answer: {}", err);
+            }
+        }
+        Command::Verify {
+            session, record, ..
+        } => {
+            let answers_path = &cache.answers_path();
+            let mut answers = match load_answers(answers_path) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    println!(
+                        "{}",
+                        format!("Couldn't read the recorded answers: {}", err)
+                            .red()
+                            .bold()
+                    );
+                    std::process::exit(1);
+                }
+            };
+            let session = get_session(session.session, session_file);
+
+            if record {
+                let mut success = true;
+                for day in &days {
+                    let input =
+                        get_auto_input(*day, session.as_ref(), true, &cache, &client_config)
+                            .unwrap_or_else(|err| exit_with_download_error(*day, err));
+                    match record_answers(get_day(*day), part.clone(), &input, &mut answers) {
+                        Ok(recorded) => {
+                            println!("{} Day {}", "Recorded".green().bold(), day);
+                            if let Some(one) = recorded.one {
+                                println!(
+                                    "\t{}: {}",
+                                    "Part 1".green(),
+                                    format!("{}", one).bold().blue()
+                                );
+                            }
+                            if let Some(two) = recorded.two {
+                                println!(
+                                    "\t{}: {}",
+                                    "Part 2".green(),
+                                    format!("{}", two).bold().blue()
+                                );
+                            }
+                        }
+                        Err(err) => {
+                            println!("{} Day {}", "Couldn't record".red().bold(), day);
+                            println!("\t{}", format!("{}", err).bold().red());
+                            success = false;
+                        }
+                    }
+                }
+
+                if let Err(err) = save_answers(answers_path, &answers) {
+                    println!(
+                        "{}",
+                        format!("Couldn't save the recorded answers: {}", err)
+                            .red()
+                            .bold()
+                    );
+                    success = false;
+                }
+                if !success {
+                    std::process::exit(1);
+                }
+            } else {
+                let reports: Vec<TestReport> = days
+                    .iter()
+                    .map(|day| {
+                        let recorded = answers.get(day).cloned().unwrap_or_default();
+                        let is_recorded = match part {
+                            Part::One => recorded.one.is_some(),
+                            Part::Two => recorded.two.is_some(),
+                            Part::Both => recorded.one.is_some() || recorded.two.is_some(),
+                        };
+                        // Parts without a recorded answer aren't run, so their input isn't needed.
+                        let input = if is_recorded {
+                            get_auto_input(*day, session.as_ref(), true, &cache, &client_config)
+                                .unwrap_or_else(|err| exit_with_download_error(*day, err))
+                        } else {
+                            String::new()
+                        };
+                        verify_day(get_day(*day), part.clone(), &input, &recorded)
+                    })
+                    .collect();
+                let unverified: Vec<String> = reports
+                    .iter()
+                    .filter(|v| is_skipped(v))
+                    .map(|v| v.day.to_string())
+                    .collect();
+
+                let passed = print_reports(&reports, &output);
+                if !unverified.is_empty() {
+                    eprintln!(
+                        "{}",
+                        format!(
+                            "No answers are recorded in {} for day(s) {}, so they weren't verified. Record them using `verify --record`.",
+                            answers_path.display(),
+                            unverified.join(", ")
+                        )
+                        .yellow()
+                        .bold()
+                    );
+                }
+                if !passed || !unverified.is_empty() {
+                    std::process::exit(1);
+                }
+            }
         }
-        ("test", _) => {
-            if !test_day(day, part) {
+        Command::Test { .. } => {
+            let reports: Vec<TestReport> = days
+                .iter()
+                .map(|day| test_day(get_day(*day), part.clone()))
+                .collect();
+
+            if !print_reports(&reports, &output) {
                 std::process::exit(1);
             }
         }
-        _ => panic!("Unexpected Subcommand."),
+        Command::FetchPuzzle {
+            day,
+            session,
+            examples_dir,
+        } => {
+            // The description of the first part is public, so a session is optional.
+            let session = get_session(session.session, session_file).unwrap_or_default();
+            eprintln!("Downloading puzzle for day {}", day);
+            let downloaded = AocClient::new(client_config.clone(), &session)
+                .map_err(DownloadError::Network)
+                .and_then(|client| client.download_puzzle(day));
+            let html = match downloaded {
+                Ok(html) => {
+                    if let Err(err) = cache.write_puzzle(day, &html) {
+                        eprintln!("Warning! couldn't save puzzle cache! {}", err);
+                    }
+                    html
+                }
+                Err(err) => match cache.read_puzzle(day) {
+                    Ok(html) => {
+                        eprintln!(
+                            "Warning! couldn't download the puzzle ({}), using the cached puzzle instead.",
+                            err
+                        );
+                        html
+                    }
+                    Err(_) => {
+                        println!(
+                            "{}",
+                            format!("Couldn't get the puzzle for day {}: {}", day, err)
+                                .red()
+                                .bold()
+                        );
+                        std::process::exit(1);
+                    }
+                },
+            };
+
+            let puzzle = parse_puzzle(&html);
+            if puzzle.parts.is_empty() {
+                println!(
+                    "{}",
+                    "The page doesn't contain a puzzle description."
+                        .red()
+                        .bold()
+                );
+                std::process::exit(1);
+            }
+            println!("{}\n", puzzle.text);
+
+            // Examples are often repeated in the second part.
+            let mut examples: Vec<&String> = vec![];
+            for (part, description) in puzzle.parts.iter().enumerate() {
+                println!("{}", format!("Part {}", part + 1).bold());
+                for example in &description.examples {
+                    let number = match examples.iter().position(|v| *v == example) {
+                        Some(i) => i + 1,
+                        None => {
+                            examples.push(example);
+                            examples.len()
+                        }
+                    };
+                    println!("\tExample {}: {} lines", number, example.lines().count());
+                }
+                match description.answers.last() {
+                    Some(answer) => println!(
+                        "\tSuggested expected result: {} (found {})",
+                        answer.bold().blue(),
+                        description.answers.join(", ")
+                    ),
+                    None => println!("\tNo example answers found."),
+                }
+            }
+
+            let examples_dir =
+                examples_dir.unwrap_or_else(|| year_dir(client_config.year).join("test_inputs"));
+            if !examples_dir.is_dir() {
+                println!(
+                    "{}",
+                    format!(
+                        "{} doesn't exist, so the examples weren't stored.",
+                        examples_dir.display()
+                    )
+                    .yellow()
+                );
+                return;
+            }
+            for (i, example) in examples.iter().enumerate() {
+                let mut files =
+                    vec![examples_dir.join(format!("test{:02}_example{}.txt", day, i + 1))];
+                if i == 0 {
+                    files.push(examples_dir.join(format!("test{:02}.txt", day)));
+                }
+                for file in files {
+                    if fs::read_to_string(&file).is_ok_and(|v| !v.trim().is_empty()) {
+                        println!(
+                            "{} {}, it already exists",
+                            "Skipped".yellow().bold(),
+                            file.display()
+                        );
+                        continue;
+                    }
+                    match fs::write(&file, example) {
+                        Ok(()) => println!(
+                            "{} example {} in {}",
+                            "Stored".green().bold(),
+                            i + 1,
+                            file.display()
+                        ),
+                        Err(err) => println!(
+                            "{} {}: {}",
+                            "Couldn't store".red().bold(),
+                            file.display(),
+                            err
+                        ),
+                    }
+                }
+            }
+        }
+        Command::NewDay {
+            day,
+            data,
+            one,
+            two,
+            example: example_file,
+            from_puzzle,
+            template,
+            days_dir,
+        } => {
+            let fail = |message: String| -> ! {
+                println!("{}", message.red().bold());
+                std::process::exit(1);
+            };
+
+            let days_dir = days_dir.unwrap_or_else(|| year_dir(client_config.year));
+            let day_path = days_dir.join(format!("d{:02}.rs", day));
+            match fs::read_to_string(&day_path) {
+                Ok(source) if !is_stub(&source) => fail(format!(
+                    "Day {} is already implemented, refusing to overwrite {}.",
+                    day,
+                    day_path.display()
+                )),
+                Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                    fail(format!("Couldn't read {}: {}", day_path.display(), err))
+                }
+                _ => {}
+            }
+
+            let template = match template.or(config.day_template.value) {
+                Some(path) => fs::read_to_string(&path).unwrap_or_else(|err| {
+                    fail(format!(
+                        "Couldn't read the template {}: {}",
+                        path.display(),
+                        err
+                    ))
+                }),
+                None => DAY_TEMPLATE.to_owned(),
+            };
+
+            let mut scaffold = DayScaffold::new(day);
+            scaffold.data = data;
+            let mut example = None;
+            if from_puzzle {
+                let html = cache.read_puzzle(day).unwrap_or_else(|err| {
+                    fail(format!(
+                        "Couldn't read the puzzle of day {}, download it using the fetch-puzzle command first: {}",
+                        day, err
+                    ))
+                });
+                let puzzle = parse_puzzle(&html);
+                let part = |i: usize| puzzle.parts.get(i).and_then(|v| v.answers.last().cloned());
+                scaffold.one = part(0);
+                scaffold.two = part(1);
+                example = puzzle
+                    .parts
+                    .first()
+                    .and_then(|v| v.examples.first().cloned());
+            }
+            if one.is_some() {
+                scaffold.one = one;
+            }
+            if two.is_some() {
+                scaffold.two = two;
+            }
+            if let Some(file) = example_file {
+                example = Some(fs::read_to_string(&file).unwrap_or_else(|err| {
+                    fail(format!(
+                        "Couldn't read the example {}: {}",
+                        file.display(),
+                        err
+                    ))
+                }));
+            }
+
+            // The test input has to exist, since the day includes it.
+            let test_path = days_dir
+                .join("test_inputs")
+                .join(format!("test{:02}.txt", day));
+            let existing = fs::read_to_string(&test_path).unwrap_or_default();
+            if !existing.trim().is_empty() {
+                if example.is_some_and(|v| v != existing) {
+                    println!(
+                        "{} {}, it already exists",
+                        "Skipped".yellow().bold(),
+                        test_path.display()
+                    );
+                }
+            } else if let Err(err) = fs::write(&test_path, example.unwrap_or_default()) {
+                fail(format!("Couldn't write {}: {}", test_path.display(), err));
+            }
+
+            if let Err(err) = fs::write(&day_path, render_day(&template, &scaffold)) {
+                fail(format!("Couldn't write {}: {}", day_path.display(), err));
+            }
+            println!("{} {}", "Generated".green().bold(), day_path.display());
+            println!(
+                "Once it's implemented, remove `const IMPLEMENTED: bool = false;` and day{:02} from day_tests! in {}.",
+                day,
+                days_dir.join("mod.rs").display()
+            );
+        }
+        Command::List { tag, .. } => {
+            print_calendar(
+                client_config.year,
+                &calendar(get_event()),
+                &days,
+                tag.as_deref(),
+            );
+        }
+        Command::Login { session } => {
+            let session = match session.or_else(|| env::var("AOC_SESSION").ok()) {
+                Some(session) => session,
+                None => {
+                    eprintln!("Please paste your session cookie, and then press Enter");
+                    let mut session = String::new();
+                    if let Err(err) = io::stdin().read_line(&mut session) {
+                        panic!("Error encountered while trying to read stdin: {}", err);
+                    }
+                    session
+                }
+            };
+            let session = session.trim();
+            if session.is_empty() {
+                println!("{}", "No session was provided.".red().bold());
+                std::process::exit(1);
+            }
+
+            let user = match AocClient::new(client_config.clone(), session)
+                .map_err(DownloadError::Network)
+                .and_then(|client| client.user())
+            {
+                Ok(user) => user,
+                Err(err) => {
+                    println!("{}", format!("Couldn't log in: {}", err).red().bold());
+                    std::process::exit(1);
+                }
+            };
+            let path = match session_file {
+                Some(path) => path,
+                None => {
+                    println!(
+                        "{}",
+                        format!(
+                            "Couldn't find a directory to store the session in, please set {} or session_file in {}.",
+                            SESSION_FILE_VAR, CONFIG_FILE
+                        )
+                        .red()
+                        .bold()
+                    );
+                    std::process::exit(1);
+                }
+            };
+            if let Err(err) = save_session(path, session) {
+                println!(
+                    "{}",
+                    format!("Couldn't store the session: {}", err).red().bold()
+                );
+                std::process::exit(1);
+            }
+            println!(
+                "{} as {}, the session was stored in {}",
+                "Logged in".green().bold(),
+                user.bold().blue(),
+                path.display()
+            );
+        }
+        Command::Cache { command } => match command {
+            CacheCommand::List { .. } => {
+                println!("{} {}", "Cache:".bold(), cache.dir().display());
+                let entries: Vec<_> = cache
+                    .entries()
+                    .into_iter()
+                    .filter(|entry| days.contains(&entry.day))
+                    .collect();
+                if entries.is_empty() {
+                    println!("\tNo cached inputs.");
+                }
+                for entry in entries {
+                    let status = match entry.status {
+                        CacheStatus::Valid => entry.status.to_string().green(),
+                        CacheStatus::Unchecked => entry.status.to_string().yellow(),
+                        CacheStatus::Corrupted => entry.status.to_string().red(),
+                    };
+                    println!(
+                        "\tDay {:>2}: {:>6} bytes, {}",
+                        entry.day, entry.size, status
+                    );
+                }
+            }
+            CacheCommand::Show { day } => match cache.read_input(day) {
+                Ok(input) => print!("{}", input),
+                Err(err) => {
+                    println!(
+                        "{}",
+                        format!("Couldn't read the cached input of day {}: {}", day, err)
+                            .red()
+                            .bold()
+                    );
+                    std::process::exit(1);
+                }
+            },
+            CacheCommand::Prefetch { session, .. } => {
+                let session = match get_session(session.session, session_file) {
+                    Some(session) => session,
+                    None => {
+                        println!(
+                            "{}",
+                            format!(
+                                "Couldn't prefetch the inputs: {}",
+                                DownloadError::MissingSession
+                            )
+                            .red()
+                            .bold()
+                        );
+                        std::process::exit(1);
+                    }
+                };
+
+                let now = SystemClock.now();
+                let missing: Vec<u8> = days
+                    .iter()
+                    .copied()
+                    .filter(|day| unlock_time(client_config.year, *day) <= now)
+                    .filter(|day| cache.read_input(*day).is_err())
+                    .collect();
+                if missing.is_empty() {
+                    println!("{}", "All unlocked days are cached already.".green().bold());
+                }
+
+                let mut success = true;
+                for (i, day) in missing.iter().enumerate() {
+                    if i > 0 {
+                        thread::sleep(PREFETCH_DELAY);
+                    }
+                    let cached = download_input(*day, &session, &client_config)
+                        .map_err(|err| err.to_string())
+                        .and_then(|input| {
+                            cache
+                                .write_input(*day, &input)
+                                .map_err(|err| err.to_string())
+                        });
+                    match cached {
+                        Ok(()) => println!("{} Day {}", "Cached".green().bold(), day),
+                        Err(err) => {
+                            println!("{} Day {}: {}", "Couldn't cache".red().bold(), day, err);
+                            success = false;
+                        }
+                    }
+                }
+                if !success {
+                    std::process::exit(1);
+                }
+            }
+            CacheCommand::Import { day, file } => {
+                let imported =
+                    fs::read_to_string(&file).and_then(|input| cache.write_input(day, &input));
+                match imported {
+                    Ok(()) => println!(
+                        "{} {} as the input of day {}",
+                        "Imported".green().bold(),
+                        file.display(),
+                        day
+                    ),
+                    Err(err) => {
+                        println!(
+                            "{}",
+                            format!("Couldn't import {}: {}", file.display(), err)
+                                .red()
+                                .bold()
+                        );
+                        std::process::exit(1);
+                    }
+                }
+            }
+            CacheCommand::Purge { .. } => {
+                let mut removed = 0;
+                for day in &days {
+                    match cache
+                        .remove_input(*day)
+                        .and_then(|input| Ok(cache.remove_puzzle(*day)? || input))
+                    {
+                        Ok(true) => {
+                            println!("{} Day {}", "Removed".green().bold(), day);
+                            removed += 1;
+                        }
+                        Ok(false) => {}
+                        Err(err) => {
+                            println!("{} Day {}: {}", "Couldn't remove".red().bold(), day, err);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                if removed == 0 {
+                    println!("No cached inputs or puzzles to remove.");
+                }
+            }
+        },
+        Command::Config {
+            command: ConfigCommand::Show,
+        } => {
+            println!("# Configuration files, in order of precedence:");
+            for (path, _) in &files {
+                match path.exists() {
+                    true => println!("#   {}", path.display()),
+                    false => println!("#   {} (doesn't exist)", path.display()),
+                }
+            }
+            println!("{}", config.show());
+        }
+        Command::Completions { .. } | Command::Man { .. } => unreachable!(),
+    }
+}
+
+/// Waits until the day unlocks, showing a countdown.
+fn wait_for_day(day: u8, year: u16, clock: &impl Clock) {
+    let mut waited = false;
+    wait_for_unlock(clock, year, day, |remaining| {
+        waited = true;
+        let secs = remaining.as_secs_f64().ceil() as u64;
+        let countdown = format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+        eprint!("\rDay {} unlocks in {} ", day, countdown.bold());
+        let _ = io::stderr().flush();
+    });
+    if waited {
+        eprintln!("\rDay {} is unlocked!{}", day, " ".repeat(16));
     }
 }
 
+/// Gets the session from the session argument, the AOC_SESSION environment
+/// variable or the session stored by the login command, in that order.
+fn get_session(session: Option<String>, session_file: Option<&Path>) -> Option<String> {
+    session
+        .or_else(|| env::var("AOC_SESSION").ok())
+        .or_else(|| match load_session(session_file?) {
+            Ok(session) => session,
+            Err(err) => {
+                println!(
+                    "{}",
+                    format!("Couldn't read the stored session: {}", err)
+                        .red()
+                        .bold()
+                );
+                std::process::exit(1);
+            }
+        })
+}
+
+/// Loads the submission history, warning if it can't be read.
+fn load_submission_history(cache: &Cache) -> History {
+    load_history(&cache.history_path()).unwrap_or_else(|err| {
+        eprintln!("Warning! couldn't read the submission history: {}", err);
+        History::new()
+    })
+}
+
+/// Prints test reports either as text, followed by how many days passed,
+/// or as records in a machine readable format.
+/// Returns false if any of them failed.
+fn print_reports(reports: &[TestReport], output: &OutputFormat) -> bool {
+    if *output != OutputFormat::Text {
+        let records: Vec<PartRecord> = reports.iter().flat_map(|v| v.records()).collect();
+        return print_records(&records, output);
+    }
+
+    let mut passed = 0;
+    let mut skipped = 0;
+    for report in reports {
+        report.print();
+        if is_skipped(report) {
+            skipped += 1;
+        } else if report.passed() {
+            passed += 1;
+        }
+    }
+
+    if reports.len() > 1 {
+        println!(
+            "{} {} of {} days{}",
+            "Passed".green().bold(),
+            format!("{}", passed).bold().blue(),
+            format!("{}", reports.len()).bold().blue(),
+            match skipped {
+                0 => String::new(),
+                _ => format!(", {} skipped", skipped).yellow().bold().to_string(),
+            }
+        );
+    }
+
+    passed + skipped == reports.len()
+}
+
+/// Whether none of the parts of a report had an answer to test against.
+fn is_skipped(report: &TestReport) -> bool {
+    (1..=2).all(|part| report.part_status(part) == TestStatus::Skipped)
+}
+
+/// Prints records in a machine readable format.
+/// Returns false if any of them failed.
+fn print_records(records: &[PartRecord], output: &OutputFormat) -> bool {
+    println!("{}", format_records(records, output));
+    records.iter().all(|v| v.is_success())
+}
+
+/// The directory the days of an event are stored in.
+fn year_dir(year: u16) -> PathBuf {
+    PathBuf::from(format!("src/y{}", year))
+}
+
+/// Reads the input from a file, exiting with an error message if that fails.
+fn read_input_file(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|err| {
+        println!(
+            "{}",
+            format!("Couldn't read the input file {}: {}", path.display(), err)
+                .red()
+                .bold()
+        );
+        std::process::exit(1);
+    })
+}
+
 fn get_stdin_day_input(day: u8) -> String {
     let mut input = String::new();
     let stdin = std::io::stdin();
 
-    println!(
+    eprintln!(
         "Please paste your input for day {}, and then press {}",
         day,
         match cfg!(windows) {
@@ -179,81 +1092,84 @@ fn get_stdin_day_input(day: u8) -> String {
                     break;
                 }
             }
-            Err(err) => panic!("Error encountered while trying to read stdin: {}", err),
+            Err(err) => {
+                println!(
+                    "{}",
+                    format!("Couldn't read the input from stdin: {}", err)
+                        .red()
+                        .bold()
+                );
+                std::process::exit(1);
+            }
         }
     }
 
     input
 }
 
-fn download_input(day: u8, session: &String) -> Result<String, reqwest::Error> {
-    println!("Downloading input for day {}", day);
+fn download_input(day: u8, session: &str, config: &ClientConfig) -> Result<String, DownloadError> {
+    eprintln!("Downloading input for day {}", day);
 
-    let cookie_jar = Jar::default();
-    cookie_jar.add_cookie_str(
-        format!("session={}; Domain=adventofcode.com", session).as_ref(),
-        &"https://adventofcode.com/".parse::<reqwest::Url>().unwrap(),
-    );
-    let client = Client::builder()
-        .https_only(true)
-        .cookie_provider(Arc::new(cookie_jar))
-        .build()?;
-
-    let response = client
-        .get(format!("https://adventofcode.com/2023/day/{}/input", day))
-        .header(
-            USER_AGENT,
-            "https://github.com/LeMoonStar/AoC23 aoc23@unitcore.de",
-        )
-        .send()?;
-
-    if !response.status().is_success() {
-        panic!("Server error or invalid session.");
-    }
-
-    response.text()
+    AocClient::new(config.clone(), session)
+        .map_err(DownloadError::Network)?
+        .download_input(day)
 }
 
-fn get_auto_input(day: u8, session: Option<&String>, cache: bool) -> String {
-    let cache_str = &format!("./.aoc23_cache/input{:02}.txt", day);
-    let cache_path: &Path = Path::new(cache_str);
-    match cache {
-        true => match fs::read_to_string(cache_path) {
-            Ok(input) => input,
-            Err(_) => {
-                if let Some(session) = session {
-                    match download_input(day, session) {
-                        Ok(input) => {
-                            let _ = fs::create_dir(Path::new("./.aoc23_cache"));
-                            match fs::write(cache_path, &input) {
-                                Ok(_) => {}
-                                Err(err) => {
-                                    println!("Warning! couldn't save input cache!{:?}", err)
-                                }
-                            }
-                            input
-                        }
-                        Err(err) => {
-                            panic!("Error while downloading input: {:?}", err);
-                        }
-                    }
-                } else {
-                    panic!("Neither a session argument nor the AOC_SESSION environment variable were provided, and there is no cache of the day's input.");
-                }
-            }
-        },
-        false => {
-            let _ = fs::remove_file(cache_path);
-            if let Some(session) = session {
-                match download_input(day, session) {
-                    Ok(input) => input,
-                    Err(err) => {
-                        panic!("Error while downloading input: {:?}", err);
-                    }
+/// Gets the input of a day from the cache, or downloads it if it isn't cached.
+/// Without `use_cache`, the input is always downloaded and the cache is deleted,
+/// unless the download fails, in which case the stale cache is used instead.
+fn get_auto_input(
+    day: u8,
+    session: Option<&String>,
+    use_cache: bool,
+    cache: &Cache,
+    config: &ClientConfig,
+) -> Result<String, DownloadError> {
+    if use_cache {
+        match cache.read_input(day) {
+            Ok(input) => return Ok(input),
+            Err(err) if err.kind() == io::ErrorKind::InvalidData => eprintln!(
+                "Warning! the cached input for day {} is corrupted ({}), downloading it again.",
+                day, err
+            ),
+            Err(_) => {}
+        }
+    }
+
+    let downloaded = match session {
+        Some(session) => download_input(day, session, config),
+        None => Err(DownloadError::MissingSession),
+    };
+    match downloaded {
+        Ok(input) => {
+            if use_cache {
+                if let Err(err) = cache.write_input(day, &input) {
+                    eprintln!("Warning! couldn't save input cache! {}", err);
                 }
             } else {
-                panic!("Neither a session argument nor the AOC_SESSION environment variable were provided, and there is no cache of the day's input.");
+                let _ = cache.remove_input(day);
             }
+            Ok(input)
         }
+        Err(err) => match cache.read_input(day) {
+            Ok(input) => {
+                eprintln!(
+                    "Warning! couldn't download the input ({}), using the cached input instead.",
+                    err
+                );
+                Ok(input)
+            }
+            Err(_) => Err(err),
+        },
     }
 }
+
+fn exit_with_download_error(day: u8, err: DownloadError) -> ! {
+    println!(
+        "{}",
+        format!("Couldn't get the input for day {}: {}", day, err)
+            .red()
+            .bold()
+    );
+    std::process::exit(1);
+}
@@ -1,3 +1,5 @@
+use crate::SolveError;
+
 /// A general purpose struct able to store 2-Dimensional maps of Tiles.
 ///
 /// Can be parsed from a multi-line &str, if the T type implements [`From<char>`](std::convert::From).
@@ -48,20 +50,42 @@ impl<T> From<Vec<Vec<T>>> for Map<T> {
     }
 }
 
-impl<T> From<&str> for Map<T>
+impl<T> TryFrom<&str> for Map<T>
 where
-    T: From<char>,
+    T: TryFrom<char, Error = SolveError>,
 {
-    fn from(value: &str) -> Self {
+    type Error = SolveError;
+
+    /// Parses a map, one tile per character.
+    /// Errors returned by the tile parser are annotated with the position of the tile.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         let tiles: Vec<Vec<T>> = value
             .lines()
-            .map(|v| v.chars().map(|v| v.into()).collect())
-            .collect();
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| T::try_from(c).map_err(|e| e.at_line(y + 1).at_column(x + 1)))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        if tiles.is_empty() {
+            return Err(SolveError::parse("expected a map, found empty input"));
+        }
+        if let Some(y) = tiles.iter().position(|row| row.len() != tiles[0].len()) {
+            return Err(SolveError::parse(format!(
+                "expected {} tiles like in the first line, found {}",
+                tiles[0].len(),
+                tiles[y].len()
+            ))
+            .at_line(y + 1));
+        }
+
+        Ok(Self {
             dim: (tiles[0].len(), tiles.len()),
             tiles,
-        }
+        })
     }
 }
 
@@ -1,4 +1,4 @@
-use super::{Answer, Day, DayImpl};
+use super::{Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 1;
 
@@ -19,12 +19,10 @@ impl NumberType {
     fn parse_next(text: &str) -> (Option<Self>, &str) {
         let first_char = text.chars().next().unwrap();
 
-        if first_char.is_numeric() {
+        if let Some(digit) = first_char.to_digit(10) {
             (
-                Some(Self::Digit(
-                    first_char.to_digit(10).unwrap().try_into().unwrap(),
-                )),
-                &text[1..],
+                Some(Self::Digit(digit as u8)),
+                &text[first_char.len_utf8()..],
             )
         } else if text.starts_with("one") {
             (
@@ -93,19 +91,37 @@ impl NumberType {
 
 type Data = Vec<Vec<NumberType>>;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test01.txt"))
+    const TITLE: Option<&'static str> = Some("Trebuchet?!");
+    const TAGS: &'static [&'static str] = &["strings", "parsing"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![
+            TestCase {
+                name: "digits example",
+                input: include_str!("test_inputs/test01_example1.txt"),
+                one: Some(Answer::Number(142)),
+                two: None,
+            },
+            TestCase {
+                name: "spelled out example",
+                input: include_str!("test_inputs/test01_example2.txt"),
+                one: None,
+                two: Some(Answer::Number(281)),
+            },
+            TestCase {
+                name: "full input",
+                input: include_str!("test_inputs/test01.txt"),
+                one: Some(Answer::Number(55712)),
+                two: Some(Answer::Number(55413)),
+            },
+        ]
     }
 
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(55712), Answer::Number(55413))
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, input.lines().map(NumberType::parse_line).collect()))
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.lines().map(NumberType::parse_line).collect())
-    }
-
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut sum: u64 = 0;
 
         for line in data {
@@ -125,18 +141,25 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             )
         }
 
-        Answer::Number(sum)
+        Ok(Answer::Number(sum))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut sum: u64 = 0;
 
-        for digits in data {
-            sum += std::convert::Into::<u64>::into(
-                digits.first().unwrap().get_value() * 10 + digits.last().unwrap().get_value(),
-            )
+        for (y, digits) in data.iter().enumerate() {
+            let (first, last) = match (digits.first(), digits.last()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => {
+                    return Err(SolveError::unsolvable(format!(
+                        "line {} contains no digits",
+                        y + 1
+                    )))
+                }
+            };
+            sum += std::convert::Into::<u64>::into(first.get_value() * 10 + last.get_value())
         }
 
-        Answer::Number(sum)
+        Ok(Answer::Number(sum))
     }
 }
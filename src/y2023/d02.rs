@@ -1,4 +1,4 @@
-use super::{Answer, Day, DayImpl};
+use super::{parse_lines, parse_number, Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 2;
 
@@ -9,8 +9,10 @@ pub struct Handful {
     blue: u8,
 }
 
-impl From<&str> for Handful {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for Handful {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut out = Self {
             red: 0,
             green: 0,
@@ -18,48 +20,54 @@ impl From<&str> for Handful {
         };
 
         for colour_count in value.split(", ") {
-            let (num, colour) = colour_count.split_once(' ').unwrap();
-            let num = num.parse::<u8>().unwrap();
+            let (num, colour) = colour_count.split_once(' ').ok_or_else(|| {
+                SolveError::parse(format!(
+                    "expected '<count> <colour>', found '{}'",
+                    colour_count
+                ))
+            })?;
+            let num = parse_number(num)?;
             match colour {
                 "red" => out.red = num,
                 "green" => out.green = num,
                 "blue" => out.blue = num,
-                _ => panic!("unexpected input"),
+                _ => return Err(SolveError::parse(format!("unknown colour '{}'", colour))),
             }
         }
 
-        out
+        Ok(out)
     }
 }
 
 type Data = Vec<Vec<Handful>>;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test02.txt"))
-    }
+    const TITLE: Option<&'static str> = Some("Cube Conundrum");
+    const TAGS: &'static [&'static str] = &["parsing"];
 
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(8), Answer::Number(2286))
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test02.txt"),
+            one: Some(Answer::Number(8)),
+            two: Some(Answer::Number(2286)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((
             Self {},
-            input
-                .lines()
-                .map(|line| {
-                    line.split_once(": ")
-                        .unwrap()
-                        .1
-                        .split("; ")
-                        .map(|handful| handful.into())
-                        .collect()
-                })
-                .collect(),
-        )
+            parse_lines(input, |line| {
+                line.split_once(": ")
+                    .ok_or_else(|| SolveError::parse("missing game header"))?
+                    .1
+                    .split("; ")
+                    .map(Handful::try_from)
+                    .collect()
+            })?,
+        ))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut result: u64 = 0;
 
         for (game_id, game) in data.iter().enumerate() {
@@ -74,10 +82,10 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
                 result += (game_id + 1) as u64;
             }
         }
-        Answer::Number(result)
+        Ok(Answer::Number(result))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut result: u64 = 0;
 
         for game in data {
@@ -101,6 +109,6 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
 
             result += max_hand.red as u64 * max_hand.green as u64 * max_hand.blue as u64;
         }
-        Answer::Number(result)
+        Ok(Answer::Number(result))
     }
 }
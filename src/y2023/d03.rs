@@ -1,10 +1,10 @@
 use std::collections::HashMap;
 
-use super::{Answer, Day, DayImpl};
+use super::{Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 3;
 
-fn parse_map(map: &str) -> Data {
+fn parse_map(map: &str) -> Result<Data, SolveError> {
     let chars: Vec<Vec<char>> = map.lines().map(|v| v.chars().collect()).collect();
     let mut parsed_numbers: Vec<Number> = vec![];
 
@@ -19,8 +19,14 @@ fn parse_map(map: &str) -> Data {
                     current_number = c.to_digit(10).unwrap();
                     start_pos = Some(x);
                 } else {
-                    current_number *= 10;
-                    current_number += c.to_digit(10).unwrap();
+                    current_number = current_number
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(c.to_digit(10).unwrap()))
+                        .ok_or_else(|| {
+                            SolveError::parse("the number is too large")
+                                .at_line(y + 1)
+                                .at_column(start_pos.unwrap() + 1)
+                        })?;
                 }
 
                 len += 1;
@@ -47,7 +53,7 @@ fn parse_map(map: &str) -> Data {
         }
     }
 
-    (chars, parsed_numbers)
+    Ok((chars, parsed_numbers))
 }
 
 #[derive(Debug, Clone)]
@@ -60,19 +66,23 @@ pub struct Number {
 
 type Data = (Vec<Vec<char>>, Vec<Number>);
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test03.txt"))
+    const TITLE: Option<&'static str> = Some("Gear Ratios");
+    const TAGS: &'static [&'static str] = &["grid"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test03.txt"),
+            one: Some(Answer::Number(4361)),
+            two: Some(Answer::Number(467835)),
+        }]
     }
 
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(4361), Answer::Number(467835))
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, parse_map(input)?))
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, parse_map(input))
-    }
-
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut sum = 0;
 
         for number in &data.1 {
@@ -93,15 +103,15 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             }
 
             if found {
-                sum += number.num;
+                sum += number.num as u64;
                 continue;
             }
         }
 
-        Answer::Number(sum as u64)
+        Ok(Answer::Number(sum))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut sum = 0;
         let mut gears: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
 
@@ -132,6 +142,6 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             }
         }
 
-        Answer::Number(sum)
+        Ok(Answer::Number(sum))
     }
 }
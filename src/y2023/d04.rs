@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use super::{parse_lines, parse_number, Answer, Day, DayImpl, SolveError, TestCase};
+
+const CURRENT_DAY: u8 = 4;
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    card_id: u8,
+    winning_numbers: Vec<u8>,
+    numbers: Vec<u8>,
+}
+
+impl TryFrom<&str> for Card {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (header, card) = value
+            .split_once(": ")
+            .ok_or_else(|| SolveError::parse("missing card header"))?;
+
+        let (winning_list, numbers_list) = card
+            .split_once(" | ")
+            .ok_or_else(|| SolveError::parse("missing ' | ' separator"))?;
+
+        Ok(Self {
+            card_id: parse_number(
+                header
+                    .split_whitespace()
+                    .nth(1)
+                    .ok_or_else(|| SolveError::parse("missing card id"))?,
+            )?,
+            winning_numbers: winning_list
+                .split_whitespace()
+                .map(parse_number)
+                .collect::<Result<_, _>>()?,
+            numbers: numbers_list
+                .split_whitespace()
+                .map(parse_number)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Card {
+    /// How many of the numbers are winning numbers.
+    fn wins(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|v| self.winning_numbers.contains(v))
+            .count()
+    }
+}
+
+fn traverse_card_tree(tree: &mut HashMap<usize, (Vec<usize>, Option<usize>)>, id: usize) -> usize {
+    if let Some(cached) = tree.get(&id).unwrap().1 {
+        cached
+    } else {
+        let tmp = tree.get(&id).unwrap().0.len()
+            + tree
+                .get(&id)
+                .unwrap()
+                .clone()
+                .0
+                .iter()
+                .map(|i| traverse_card_tree(tree, *i))
+                .sum::<usize>();
+        tree.get_mut(&id).unwrap().1 = Some(tmp);
+        tmp
+    }
+}
+
+type Data = Vec<Card>;
+impl DayImpl<Data> for Day<CURRENT_DAY> {
+    const TITLE: Option<&'static str> = Some("Scratchcards");
+    const TAGS: &'static [&'static str] = &["dp"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test04.txt"),
+            one: Some(Answer::Number(13)),
+            two: Some(Answer::Number(30)),
+        }]
+    }
+
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        let cards = parse_lines(input, Card::try_from)?;
+        if cards.is_empty() {
+            return Err(SolveError::parse("no cards"));
+        }
+
+        // Cards are numbered in order, and never win copies of cards past the last one.
+        for (i, card) in cards.iter().enumerate() {
+            if card.card_id as usize != i + 1 {
+                return Err(SolveError::parse(format!(
+                    "expected card {}, found card {}",
+                    i + 1,
+                    card.card_id
+                ))
+                .at_line(i + 1));
+            }
+            if i + card.wins() >= cards.len() {
+                return Err(SolveError::parse(format!(
+                    "card {} wins copies of cards past the last card",
+                    card.card_id
+                ))
+                .at_line(i + 1));
+            }
+        }
+
+        Ok((Self {}, cards))
+    }
+
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        let mut score = 0;
+
+        for card in data {
+            let mut card_score = 0;
+
+            for num in &card.numbers {
+                if card.winning_numbers.contains(num) {
+                    card_score = 1.max(card_score * 2);
+                }
+            }
+
+            score += card_score;
+        }
+
+        Ok(Answer::Number(score))
+    }
+
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        let mut m: HashMap<usize, (Vec<usize>, Option<usize>)> = HashMap::new();
+
+        for (i, card) in data.iter().enumerate() {
+            let id = card.card_id as usize;
+            m.insert(i, ((id..id + card.wins()).collect(), None));
+        }
+
+        let mut sum = data.len();
+        for i in 0..data.len() {
+            sum += traverse_card_tree(&mut m, i);
+        }
+
+        Ok(Answer::Number(sum as u64))
+    }
+}
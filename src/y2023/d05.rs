@@ -1,6 +1,6 @@
-use std::str::Lines;
+use std::{iter::Enumerate, str::Lines};
 
-use super::{Answer, Day, DayImpl};
+use super::{parse_number, Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 5;
 
@@ -43,26 +43,38 @@ impl Map {
         value
     }
 
-    fn parse_ranges(lines: &mut Lines) -> Self {
+    fn parse_ranges(lines: &mut Enumerate<Lines>) -> Result<Self, SolveError> {
         let mut out = Vec::with_capacity(lines.clone().count());
 
-        for line in lines.by_ref() {
+        for (y, line) in lines.by_ref() {
             if line.is_empty() {
                 break;
             }
 
-            let mut splitter = line.split_whitespace();
+            let numbers = line
+                .split_whitespace()
+                .map(parse_number)
+                .collect::<Result<Vec<u32>, _>>()
+                .map_err(|e| e.at_line(y + 1))?;
+
+            if numbers.len() != 3 {
+                return Err(SolveError::parse(format!(
+                    "expected 3 numbers per range, found {}",
+                    numbers.len()
+                ))
+                .at_line(y + 1));
+            }
 
             out.push(Range {
-                destination_start: splitter.next().unwrap().parse().unwrap(),
-                source_start: splitter.next().unwrap().parse().unwrap(),
-                length: splitter.next().unwrap().parse().unwrap(),
+                destination_start: numbers[0],
+                source_start: numbers[1],
+                length: numbers[2],
             })
         }
 
         out.sort();
 
-        Self(out /*, HashMap::new()*/)
+        Ok(Self(out /*, HashMap::new()*/))
     }
 }
 
@@ -95,18 +107,20 @@ impl Almanac {
     }
 }
 
-impl From<&str> for Almanac {
-    fn from(value: &str) -> Self {
-        let mut lines = value.lines();
+impl TryFrom<&str> for Almanac {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut lines = value.lines().enumerate();
         let seeds = lines
             .next()
-            .unwrap()
-            .split_once(": ")
-            .unwrap()
+            .and_then(|(_, line)| line.split_once(": "))
+            .ok_or_else(|| SolveError::parse("missing seed list").at_line(1))?
             .1
             .split(' ')
-            .map(|v| v.parse().unwrap())
-            .collect();
+            .map(parse_number)
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at_line(1))?;
 
         let mut seed_to_soil = None;
         let mut soil_to_fertilizer = None;
@@ -116,81 +130,97 @@ impl From<&str> for Almanac {
         let mut temperature_to_humidity = None;
         let mut humidity_to_location = None;
 
-        while let Some(line) = lines.next() {
+        while let Some((y, line)) = lines.next() {
             match line {
-                "seed-to-soil map:" => seed_to_soil = Some(Map::parse_ranges(&mut lines)),
+                "seed-to-soil map:" => seed_to_soil = Some(Map::parse_ranges(&mut lines)?),
                 "soil-to-fertilizer map:" => {
-                    soil_to_fertilizer = Some(Map::parse_ranges(&mut lines))
+                    soil_to_fertilizer = Some(Map::parse_ranges(&mut lines)?)
                 }
                 "fertilizer-to-water map:" => {
-                    fertilizer_to_water = Some(Map::parse_ranges(&mut lines))
+                    fertilizer_to_water = Some(Map::parse_ranges(&mut lines)?)
                 }
-                "water-to-light map:" => water_to_light = Some(Map::parse_ranges(&mut lines)),
+                "water-to-light map:" => water_to_light = Some(Map::parse_ranges(&mut lines)?),
                 "light-to-temperature map:" => {
-                    light_to_temperature = Some(Map::parse_ranges(&mut lines))
+                    light_to_temperature = Some(Map::parse_ranges(&mut lines)?)
                 }
                 "temperature-to-humidity map:" => {
-                    temperature_to_humidity = Some(Map::parse_ranges(&mut lines))
+                    temperature_to_humidity = Some(Map::parse_ranges(&mut lines)?)
                 }
                 "humidity-to-location map:" => {
-                    humidity_to_location = Some(Map::parse_ranges(&mut lines))
+                    humidity_to_location = Some(Map::parse_ranges(&mut lines)?)
                 }
                 "" => continue,
-                _ => panic!("unexpected input"),
+                _ => {
+                    return Err(
+                        SolveError::parse(format!("unexpected line '{}'", line)).at_line(y + 1)
+                    )
+                }
             }
         }
 
-        Almanac {
+        let missing = |name: &str| SolveError::parse(format!("missing {} map", name));
+
+        Ok(Almanac {
             seeds,
-            seed_to_soil: seed_to_soil.unwrap(),
-            soil_to_fertilizer: soil_to_fertilizer.unwrap(),
-            fertilizer_to_water: fertilizer_to_water.unwrap(),
-            water_to_light: water_to_light.unwrap(),
-            light_to_temperature: light_to_temperature.unwrap(),
-            temperature_to_humidity: temperature_to_humidity.unwrap(),
-            humidity_to_location: humidity_to_location.unwrap(),
-        }
+            seed_to_soil: seed_to_soil.ok_or_else(|| missing("seed-to-soil"))?,
+            soil_to_fertilizer: soil_to_fertilizer.ok_or_else(|| missing("soil-to-fertilizer"))?,
+            fertilizer_to_water: fertilizer_to_water
+                .ok_or_else(|| missing("fertilizer-to-water"))?,
+            water_to_light: water_to_light.ok_or_else(|| missing("water-to-light"))?,
+            light_to_temperature: light_to_temperature
+                .ok_or_else(|| missing("light-to-temperature"))?,
+            temperature_to_humidity: temperature_to_humidity
+                .ok_or_else(|| missing("temperature-to-humidity"))?,
+            humidity_to_location: humidity_to_location
+                .ok_or_else(|| missing("humidity-to-location"))?,
+        })
     }
 }
 
 type Data = Almanac;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test05.txt"))
-    }
-
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(35), Answer::Number(46))
+    const TITLE: Option<&'static str> = Some("If You Give A Seed A Fertilizer");
+    const TAGS: &'static [&'static str] = &["ranges", "brute-force"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test05.txt"),
+            one: Some(Answer::Number(35)),
+            two: Some(Answer::Number(46)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, Almanac::from(input))
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, Almanac::try_from(input)?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut lowest = u32::MAX;
 
         for seed in data.seeds.clone() {
             lowest = lowest.min(data.seed_to_destination(seed));
         }
 
-        Answer::Number(lowest as u64)
+        Ok(Answer::Number(lowest as u64))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut lowest = u32::MAX;
 
         let seeds = data.seeds.clone();
         let mut iter = seeds.iter();
 
         while let Some(range_start) = iter.next() {
-            let len = *iter.next().unwrap();
+            let len = *iter.next().ok_or_else(|| {
+                SolveError::unsolvable("the seed list must consist of (start, length) pairs")
+            })?;
 
             for seed in *range_start..(*range_start + len) {
                 lowest = lowest.min(data.seed_to_destination(seed));
             }
         }
 
-        Answer::Number(lowest as u64)
+        Ok(Answer::Number(lowest as u64))
     }
 }
@@ -0,0 +1,122 @@
+use std::ops::RangeBounds;
+
+use super::{parse_number, Answer, Day, DayImpl, SolveError, TestCase};
+
+const CURRENT_DAY: u8 = 6;
+
+#[derive(Debug, Clone)]
+pub struct Race {
+    time: u64,
+    record: u64,
+}
+
+impl Race {
+    fn calculate_wins(&self) -> Result<u64, SolveError> {
+        let discriminant = (self.time as f64 / 2.0).powf(2.0) - self.record as f64;
+        // The record can't be beaten.
+        if discriminant < 0.0 {
+            return Ok(0);
+        }
+        let tmp = discriminant.sqrt();
+
+        let max = self.time as f64 / 2.0 + tmp;
+        let min = self.time as f64 / 2.0 - tmp;
+
+        let res = max.ceil() - min.max(0.0).ceil() + if tmp % 1.0 == 0.0 { -1.0 } else { 0.0 };
+
+        if !res.is_finite() {
+            return Err(SolveError::unsolvable("the race is too long"));
+        }
+        Ok(res.floor().max(0.0) as u64)
+    }
+
+    // For smaller ranges, the overhead of calculate_wins is bigger than the
+    // actual computation time of checking the entire range.
+    fn count_wins<R: RangeBounds<u64> + IntoIterator<Item = u64>>(
+        &self,
+        button_press_range: R,
+    ) -> u64 {
+        let mut wins = 0;
+
+        for button_press_time in button_press_range {
+            if self.check_race(button_press_time) {
+                wins += 1;
+            }
+        }
+
+        wins
+    }
+
+    fn check_race(&self, button_press_time: u64) -> bool {
+        (self.time - button_press_time) as u128 * button_press_time as u128 > self.record as u128
+    }
+}
+
+impl Race {
+    fn parse_number_list(line: Option<&str>, y: usize) -> Result<Vec<u64>, SolveError> {
+        line.ok_or_else(|| SolveError::parse("missing line").at_line(y))?
+            .split_whitespace()
+            .skip(1)
+            .map(parse_number)
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at_line(y))
+    }
+
+    fn parse_race_list(input: &str) -> Result<Vec<Self>, SolveError> {
+        let mut lines = input.lines();
+        let time_list = Self::parse_number_list(lines.next(), 1)?;
+        let distance_list = Self::parse_number_list(lines.next(), 2)?;
+
+        Ok(time_list
+            .into_iter()
+            .zip(distance_list)
+            .map(|(time, record)| Self { time, record })
+            .collect())
+    }
+}
+
+type Data = Vec<Race>;
+impl DayImpl<Data> for Day<CURRENT_DAY> {
+    const TITLE: Option<&'static str> = Some("Wait For It");
+    const TAGS: &'static [&'static str] = &["math"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test06.txt"),
+            one: Some(Answer::Number(288)),
+            two: Some(Answer::Number(71503)),
+        }]
+    }
+
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, Race::parse_race_list(input)?))
+    }
+
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        Ok(Answer::Number(
+            data.iter().map(|v| v.count_wins(0..v.time)).product(),
+        ))
+    }
+
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        // Appends the digits of b to a.
+        let concat = |a: u64, b: u64| {
+            10_u64
+                .checked_pow(b.to_string().len() as u32)
+                .and_then(|v| a.checked_mul(v))
+                .and_then(|v| v.checked_add(b))
+                .ok_or_else(|| SolveError::unsolvable("the numbers are too large"))
+        };
+        let race = data
+            .iter()
+            .try_fold(Race { record: 0, time: 0 }, |race, v| {
+                Ok::<_, SolveError>(Race {
+                    record: concat(race.record, v.record)?,
+                    time: concat(race.time, v.time)?,
+                })
+            })?;
+
+        Ok(Answer::Number(race.calculate_wins()?))
+    }
+}
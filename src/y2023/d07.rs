@@ -1,6 +1,6 @@
 use std::{cmp::Ordering, collections::HashMap};
 
-use super::{Answer, Day, DayImpl};
+use super::{parse_lines, parse_number, Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 7;
 
@@ -46,9 +46,11 @@ impl Card {
     }
 }
 
-impl From<char> for Card {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Card {
+    type Error = SolveError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             'A' => Self::A,
             'K' => Self::K,
             'Q' => Self::Q,
@@ -62,8 +64,8 @@ impl From<char> for Card {
             '4' => Self::N4,
             '3' => Self::N3,
             '2' => Self::N2,
-            _ => panic!("Invalid Input"),
-        }
+            _ => return Err(SolveError::parse(format!("invalid card '{}'", value))),
+        })
     }
 }
 
@@ -134,7 +136,7 @@ impl Hand {
         }
 
         let mut cards: Vec<(Card, u8)> = counted_cards.into_iter().collect();
-        cards.sort_by(|a, b| a.1.cmp(&b.1));
+        cards.sort_by_key(|a| a.1);
         match cards.len() {
             1 => HandType::FiveOfAKind,
             5 => match cards.contains(&(Card::J, 1)) {
@@ -175,31 +177,54 @@ impl Hand {
     }
 }
 
-impl From<&str> for Hand {
-    fn from(value: &str) -> Self {
-        let (cards_str, bid_str) = value.split_once(' ').unwrap();
-        Self {
-            cards: cards_str.chars().map(|v| v.into()).collect(),
-            bid: bid_str.parse().unwrap(),
+impl TryFrom<&str> for Hand {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (cards_str, bid_str) = value
+            .split_once(' ')
+            .ok_or_else(|| SolveError::parse("expected '<cards> <bid>'"))?;
+
+        let cards = cards_str
+            .chars()
+            .enumerate()
+            .map(|(x, c)| Card::try_from(c).map_err(|e| e.at_column(x + 1)))
+            .collect::<Result<Vec<Card>, _>>()?;
+
+        // The hand type detection relies on hands always consisting of five cards.
+        if cards.len() != 5 {
+            return Err(SolveError::parse(format!(
+                "expected 5 cards, found {}",
+                cards.len()
+            )));
         }
+
+        Ok(Self {
+            cards,
+            bid: parse_number(bid_str)?,
+        })
     }
 }
 
 type Data = Vec<Hand>;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test07.txt"))
-    }
+    const TITLE: Option<&'static str> = Some("Camel Cards");
+    const TAGS: &'static [&'static str] = &["sorting"];
 
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(6440), Answer::Number(5905))
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test07.txt"),
+            one: Some(Answer::Number(6440)),
+            two: Some(Answer::Number(5905)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.lines().map(|v| v.into()).collect())
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, parse_lines(input, Hand::try_from)?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut data: Vec<(Hand, HandType)> =
             data.iter().map(|v| (v.clone(), v.get_type())).collect();
 
@@ -208,13 +233,15 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             other => other,
         });
 
-        Answer::Number(data.iter().enumerate().fold(0, |acc, (rank, hand)| {
-            acc + ((rank + 1) * hand.0.bid as usize)
-        }) as u64)
+        Ok(Answer::Number(
+            data.iter().enumerate().fold(0, |acc, (rank, hand)| {
+                acc + ((rank + 1) * hand.0.bid as usize)
+            }) as u64,
+        ))
     }
 
     // This took soooooooooooooo much debugging
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut data: Vec<(Hand, HandType)> = data
             .iter()
             .map(|v| (v.clone(), v.get_part2_type()))
@@ -225,8 +252,10 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             other => other,
         });
 
-        Answer::Number(data.iter().enumerate().fold(0, |acc, (rank, hand)| {
-            acc + ((rank + 1) * hand.0.bid as usize)
-        }) as u64)
+        Ok(Answer::Number(
+            data.iter().enumerate().fold(0, |acc, (rank, hand)| {
+                acc + ((rank + 1) * hand.0.bid as usize)
+            }) as u64,
+        ))
     }
 }
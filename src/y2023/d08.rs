@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use super::{Answer, Day, DayImpl, SolveError, TestCase};
+
+const CURRENT_DAY: u8 = 8;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    name: [char; 3],
+    left: [char; 3],
+    right: [char; 3],
+}
+
+impl TryFrom<&str> for Node {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (name_str, connections_str) = value
+            .split_once(" = ")
+            .ok_or_else(|| SolveError::parse("expected '<node> = (<left>, <right>)'"))?;
+        let (conn_left, conn_right) = connections_str
+            .split_once(", ")
+            .ok_or_else(|| SolveError::parse("expected '(<left>, <right>)'"))?;
+
+        Ok(Self {
+            name: Self::parse_name(name_str)?,
+            left: Self::parse_name(conn_left.trim_start_matches('('))?,
+            right: Self::parse_name(conn_right.trim_end_matches(')'))?,
+        })
+    }
+}
+
+impl Node {
+    fn parse_name(name: &str) -> Result<[char; 3], SolveError> {
+        let mut chars = name.chars();
+
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some(a), Some(b), Some(c), None) => Ok([a, b, c]),
+            _ => Err(SolveError::parse(format!(
+                "expected a three character node name, found '{}'",
+                name
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    directions: Vec<Direction>,
+    nodes: HashMap<[char; 3], Node>,
+}
+
+impl Map {
+    fn follow_path_to_goal(
+        &self,
+        mut node: [char; 3],
+        goal: [Option<char>; 3],
+    ) -> Result<u64, SolveError> {
+        let mut steps = 0;
+
+        if self.directions.is_empty() {
+            return Err(SolveError::unsolvable("there are no directions to follow"));
+        }
+        // There are only this many combinations of a node and the position in the
+        // directions, so a path taking longer is a cycle which never reaches the goal.
+        let limit = (self.nodes.len() * self.directions.len()) as u64;
+
+        for dir in self.directions.iter().cycle() {
+            let current = self.nodes.get(&node).ok_or_else(|| {
+                SolveError::unsolvable(format!(
+                    "the path leads to the unknown node {}",
+                    node.iter().collect::<String>()
+                ))
+            })?;
+            node = match dir {
+                Direction::Left => current.left,
+                Direction::Right => current.right,
+            };
+
+            steps += 1;
+
+            if match goal {
+                [None, None, Some(c)] => node[2] == c,
+                [Some(a), Some(b), Some(c)] => node == [a, b, c],
+                _ => unimplemented!("This part should never trip"),
+            } {
+                break;
+            }
+            if steps >= limit {
+                return Err(SolveError::unsolvable(
+                    "the path runs in circles without reaching the goal",
+                ));
+            }
+        }
+
+        Ok(steps)
+    }
+}
+
+impl TryFrom<&str> for Map {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut lines = value.lines();
+        Ok(Self {
+            directions: lines
+                .next()
+                .ok_or_else(|| SolveError::parse("missing directions").at_line(1))?
+                .chars()
+                .enumerate()
+                .map(|(x, v)| match v {
+                    'L' => Ok(Direction::Left),
+                    'R' => Ok(Direction::Right),
+                    _ => Err(SolveError::parse(format!("invalid direction '{}'", v))
+                        .at_line(1)
+                        .at_column(x + 1)),
+                })
+                .collect::<Result<_, _>>()?,
+            nodes: lines
+                .enumerate()
+                .skip(1)
+                .map(|(y, v)| {
+                    // The direction line and the empty line have already been consumed.
+                    let n = Node::try_from(v).map_err(|e| e.at_line(y + 2))?;
+                    Ok((n.name, n))
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+type Data = Map;
+impl DayImpl<Data> for Day<CURRENT_DAY> {
+    const TITLE: Option<&'static str> = Some("Haunted Wasteland");
+    const TAGS: &'static [&'static str] = &["graph", "math"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![
+            TestCase {
+                name: "branching example",
+                input: include_str!("test_inputs/test08_example1.txt"),
+                one: Some(Answer::Number(2)),
+                two: None,
+            },
+            TestCase {
+                name: "repeating example",
+                input: include_str!("test_inputs/test08_example2.txt"),
+                one: Some(Answer::Number(6)),
+                two: None,
+            },
+            TestCase {
+                name: "ghost example",
+                input: include_str!("test_inputs/test08_example3.txt"),
+                one: None,
+                two: Some(Answer::Number(6)),
+            },
+            TestCase {
+                name: "full input",
+                input: include_str!("test_inputs/test08.txt"),
+                one: Some(Answer::Number(17263)),
+                two: Some(Answer::Number(14631604759649)),
+            },
+        ]
+    }
+
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, input.try_into()?))
+    }
+
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        Ok(Answer::Number(data.follow_path_to_goal(
+            ['A', 'A', 'A'],
+            [Some('Z'), Some('Z'), Some('Z')],
+        )?))
+    }
+
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        let start_nodes: Vec<[char; 3]> =
+            data.nodes.keys().filter(|v| v[2] == 'A').copied().collect();
+
+        let frequencies: Vec<u64> = start_nodes
+            .into_iter()
+            .map(|v| data.follow_path_to_goal(v, [None, None, Some('Z')]))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Answer::Number(
+            frequencies.iter().fold(1, |a, v| Self::lcm(a, *v)),
+        ))
+    }
+}
+
+impl Day<CURRENT_DAY> {
+    fn lcm(a: u64, b: u64) -> u64 {
+        (a / Self::gcd(a, b)) * b
+    }
+
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            return a;
+        }
+        Self::gcd(b, a % b)
+    }
+}
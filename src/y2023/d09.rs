@@ -0,0 +1,119 @@
+use super::{parse_lines, parse_number, Answer, Day, DayImpl, SolveError, TestCase};
+
+const CURRENT_DAY: u8 = 9;
+
+#[derive(Debug, Clone)]
+pub struct DifferencesTable {
+    layers: Vec<Vec<i64>>,
+}
+
+impl DifferencesTable {
+    // Delta time of 14 mins for this simple task cuz SOMEBODY decided to
+    // start making a lot of noise, which killed my concentration.
+    pub fn predict_previous_number(&self) -> Result<i64, SolveError> {
+        let mut n: i64 = 0;
+        for layer in self.layers.iter().rev() {
+            n = layer[0].checked_sub(n).ok_or_else(Self::too_large)?;
+        }
+
+        Ok(n)
+    }
+
+    pub fn predict_next_number(&self) -> Result<i64, SolveError> {
+        let mut n: i64 = 0;
+        for d in self.layers.iter().rev() {
+            n = n.checked_add(d[d.len() - 1]).ok_or_else(Self::too_large)?;
+        }
+
+        Ok(n)
+    }
+
+    /// Builds the table of a sequence, which mustn't be empty.
+    pub fn create(first_layer: &[i64]) -> Result<Self, SolveError> {
+        let mut layers = vec![first_layer.to_owned()];
+
+        loop {
+            let new_layer = Self::calculate_next_layer(layers.last().unwrap())?;
+            if Self::is_zero_layer(&new_layer) {
+                break;
+            }
+            layers.push(new_layer);
+        }
+
+        Ok(Self { layers })
+    }
+
+    fn calculate_next_layer(layer: &[i64]) -> Result<Vec<i64>, SolveError> {
+        layer
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(pair[0]).ok_or_else(Self::too_large))
+            .collect()
+    }
+
+    fn is_zero_layer(layer: &[i64]) -> bool {
+        for n in layer {
+            if *n != 0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn too_large() -> SolveError {
+        SolveError::unsolvable("the numbers are too large")
+    }
+}
+
+/// Sums the predictions of every sequence.
+fn sum_predictions<F>(data: &Data, predict: F) -> Result<Answer, SolveError>
+where
+    F: Fn(&DifferencesTable) -> Result<i64, SolveError>,
+{
+    let mut sum: i64 = 0;
+    for sequence in data {
+        let prediction = predict(&DifferencesTable::create(sequence)?)?;
+        sum = sum
+            .checked_add(prediction)
+            .ok_or_else(DifferencesTable::too_large)?;
+    }
+    Ok(Answer::Number(sum as u64))
+}
+
+type Data = Vec<Vec<i64>>;
+impl DayImpl<Data> for Day<CURRENT_DAY> {
+    const TITLE: Option<&'static str> = Some("Mirage Maintenance");
+    const TAGS: &'static [&'static str] = &["math"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test09.txt"),
+            one: Some(Answer::Number(114)),
+            two: Some(Answer::Number(2)),
+        }]
+    }
+
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((
+            Self {},
+            parse_lines(input, |v| {
+                let sequence: Vec<i64> = v
+                    .split_whitespace()
+                    .map(parse_number)
+                    .collect::<Result<_, _>>()?;
+                if sequence.is_empty() {
+                    return Err(SolveError::parse("expected a sequence of numbers"));
+                }
+                Ok(sequence)
+            })?,
+        ))
+    }
+
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        sum_predictions(data, DifferencesTable::predict_next_number)
+    }
+
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        sum_predictions(data, DifferencesTable::predict_previous_number)
+    }
+}
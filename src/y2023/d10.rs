@@ -1,7 +1,7 @@
 use crate::{dprintln, vprint, vprintln};
 use std::collections::HashMap;
 
-use super::{Answer, Day, DayImpl, utils::Direction};
+use super::{utils::Direction, Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 10;
 
@@ -51,9 +51,11 @@ impl Tile {
     }
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Tile {
+    type Error = SolveError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             '|' => Self::VerticalPipe,
             '-' => Self::HorizontalPipe,
             'L' => Self::NorthEastPipe,
@@ -62,8 +64,8 @@ impl From<char> for Tile {
             'F' => Self::SouthEastPipe,
             '.' => Self::Ground,
             'S' => Self::Start,
-            _ => panic!("Invalid input"),
-        }
+            _ => return Err(SolveError::parse(format!("invalid tile '{}'", value))),
+        })
     }
 }
 
@@ -106,6 +108,8 @@ impl Map {
 
     fn get_connected_neighbours(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
         let mut neighbours = Vec::with_capacity(4);
+        // The map can't be empty, since it contains the start tile.
+        let upper_limit = (self.dimensions.0 - 1, self.dimensions.1 - 1);
 
         dprintln!(
             "  Considered Dirs: {:?}",
@@ -117,9 +121,9 @@ impl Map {
                 "    {:?}({:?}) -> {:?}",
                 dir,
                 pos,
-                dir.walk_pos(pos, self.dimensions)
+                dir.walk_pos(pos, upper_limit)
             );
-            if let Some(neighbour) = dir.walk_pos(pos, self.dimensions) {
+            if let Some(neighbour) = dir.walk_pos(pos, upper_limit) {
                 dprintln!("      {:?}: {:?}", dir, neighbour);
                 dprintln!(
                     "        Connects to: {:?}",
@@ -139,9 +143,11 @@ impl Map {
     }
 }
 
-impl From<&str> for Map {
-    fn from(value: &str) -> Self {
-        let mut start_pos = (0, 0);
+impl TryFrom<&str> for Map {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut start_pos = None;
         let tiles: Vec<Vec<Tile>> = value
             .lines()
             .enumerate()
@@ -149,45 +155,80 @@ impl From<&str> for Map {
                 l.chars()
                     .enumerate()
                     .map(|(x, c)| {
-                        let t = Tile::from(c);
+                        let t = Tile::try_from(c).map_err(|e| e.at_line(y + 1).at_column(x + 1))?;
                         if t == Tile::Start {
-                            start_pos = (x, y)
+                            start_pos = Some((x, y))
                         }
-                        t
+                        Ok(t)
                     })
                     .collect()
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        // A map with a start tile can never be empty.
+        let start_pos = start_pos.ok_or_else(|| SolveError::parse("missing start tile 'S'"))?;
+        if let Some(y) = tiles.iter().position(|row| row.len() != tiles[0].len()) {
+            return Err(SolveError::parse(format!(
+                "expected {} tiles like in the first line, found {}",
+                tiles[0].len(),
+                tiles[y].len()
+            ))
+            .at_line(y + 1));
+        }
+
+        Ok(Self {
             dimensions: (tiles[0].len(), tiles.len()),
             tiles,
             start_pos,
-        }
+        })
     }
 }
 
 type Data = Map;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test10.txt"))
-    }
+    const TITLE: Option<&'static str> = Some("Pipe Maze");
+    const TAGS: &'static [&'static str] = &["grid", "graph"];
 
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(80), Answer::Number(10))
+    fn test_cases() -> Vec<TestCase> {
+        vec![
+            TestCase {
+                name: "simple loop",
+                input: include_str!("test_inputs/test10_example1.txt"),
+                one: Some(Answer::Number(4)),
+                two: None,
+            },
+            TestCase {
+                name: "complex loop",
+                input: include_str!("test_inputs/test10_example2.txt"),
+                one: Some(Answer::Number(8)),
+                two: None,
+            },
+            TestCase {
+                name: "enclosed tiles",
+                input: include_str!("test_inputs/test10_example3.txt"),
+                one: None,
+                two: Some(Answer::Number(4)),
+            },
+            TestCase {
+                name: "larger loop",
+                input: include_str!("test_inputs/test10.txt"),
+                one: Some(Answer::Number(80)),
+                two: Some(Answer::Number(10)),
+            },
+        ]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.into())
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, input.try_into()?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let l = data.get_loop(data.start_pos);
 
-        Answer::Number(*l.values().max().unwrap() as u64)
+        Ok(Answer::Number(*l.values().max().unwrap() as u64))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut area = 0;
         let l = data.get_loop(data.start_pos);
 
@@ -271,6 +312,6 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             }
             vprintln!();
         }
-        Answer::Number(area)
+        Ok(Answer::Number(area))
     }
 }
@@ -1,6 +1,6 @@
 use crate::dprintln;
 
-use super::{Answer, Day, DayImpl};
+use super::{Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 11;
 
@@ -104,19 +104,23 @@ impl From<&str> for Map {
 
 type Data = Map;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test11.txt"))
+    const TITLE: Option<&'static str> = Some("Cosmic Expansion");
+    const TAGS: &'static [&'static str] = &["grid", "geometry"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test11.txt"),
+            one: Some(Answer::Number(374)),
+            two: Some(Answer::Number(82000210)),
+        }]
     }
 
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(374), Answer::Number(82000210))
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, input.into()))
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.into())
-    }
-
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         data.expand(1);
         let mut sum = 0;
         for a in 0..data.stars.len() {
@@ -125,10 +129,10 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             }
         }
 
-        Answer::Number(sum)
+        Ok(Answer::Number(sum))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         // I have no clue why I need the -1 here...
         // am just too tired today.
         data.expand(1000000 - 1);
@@ -140,6 +144,6 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             }
         }
 
-        Answer::Number(sum)
+        Ok(Answer::Number(sum))
     }
 }
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::dprintln;
 
-use super::{Answer, Day, DayImpl};
+use super::{parse_lines, parse_number, Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 12;
 
@@ -13,13 +13,15 @@ pub enum SpringState {
     Unknown,
 }
 
-impl From<char> for SpringState {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for SpringState {
+    type Error = SolveError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '.' => Self::Operational,
-            '#' => Self::Damaged,
-            '?' => Self::Unknown,
-            _ => panic!("Invalid input."),
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(SolveError::parse(format!("invalid spring '{}'", value))),
         }
     }
 }
@@ -104,47 +106,62 @@ impl Record {
     }
 }
 
-impl From<&str> for Record {
-    fn from(value: &str) -> Self {
-        let (springs_str, group_str) = value.split_once(' ').unwrap();
-
-        Self {
-            springs: springs_str.chars().map(|v| v.into()).collect(),
-            groups: group_str.split(',').map(|v| v.parse().unwrap()).collect(),
-        }
+impl TryFrom<&str> for Record {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (springs_str, group_str) = value
+            .split_once(' ')
+            .ok_or_else(|| SolveError::parse("expected '<springs> <groups>'"))?;
+
+        Ok(Self {
+            springs: springs_str
+                .chars()
+                .enumerate()
+                .map(|(x, v)| SpringState::try_from(v).map_err(|e| e.at_column(x + 1)))
+                .collect::<Result<_, _>>()?,
+            groups: group_str
+                .split(',')
+                .map(parse_number)
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
 type Data = Vec<Record>;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test12.txt"))
-    }
-
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(21), Answer::Number(525152))
+    const TITLE: Option<&'static str> = Some("Hot Springs");
+    const TAGS: &'static [&'static str] = &["dp"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test12.txt"),
+            one: Some(Answer::Number(21)),
+            two: Some(Answer::Number(525152)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.lines().map(|v| v.into()).collect())
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, parse_lines(input, Record::try_from)?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
-        Answer::Number(
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        Ok(Answer::Number(
             data.iter()
                 .map(|v| v.find_possible_solutions(0, 0, 0, &mut None))
                 .sum(),
-        )
+        ))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
-        Answer::Number(
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        Ok(Answer::Number(
             data.iter_mut()
                 .map(|v| {
                     v.unfold()
                         .find_possible_solutions(0, 0, 0, &mut Some(HashMap::new()))
                 })
                 .sum(),
-        )
+        ))
     }
 }
@@ -1,6 +1,6 @@
 use crate::dprintln;
 
-use super::{Answer, Day, DayImpl};
+use super::{parse_blocks, Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 13;
 
@@ -18,12 +18,14 @@ pub enum Reflection {
     OnYAxis(usize, usize),
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = SolveError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '.' => Self::Ash,
-            '#' => Self::Rock,
-            _ => panic!("Invalid input."),
+            '.' => Ok(Self::Ash),
+            '#' => Ok(Self::Rock),
+            _ => Err(SolveError::parse(format!("invalid tile '{}'", value))),
         }
     }
 }
@@ -114,8 +116,7 @@ impl Pattern {
                         reflection_x + offset,
                         y,
                     );
-                    if self.get(reflection_x - offset - 1, y)
-                        != self.get(reflection_x + offset, y)
+                    if self.get(reflection_x - offset - 1, y) != self.get(reflection_x + offset, y)
                     {
                         dprintln!("    Not a reflection.");
                         is_reflection = false;
@@ -167,8 +168,7 @@ impl Pattern {
                         x,
                         reflection_y + offset
                     );
-                    if self.get(x, reflection_y - offset - 1)
-                        != self.get(x, reflection_y + offset)
+                    if self.get(x, reflection_y - offset - 1) != self.get(x, reflection_y + offset)
                     {
                         dprintln!("    Not a reflection.");
                         is_reflection = false;
@@ -190,8 +190,7 @@ impl Pattern {
             }
         }
 
-        panic!("Pattern without Reflection.");
-        //None
+        None
     }
 
     // This adaptation literally took about 3 minutes at most.
@@ -229,8 +228,7 @@ impl Pattern {
                         reflection_x + offset,
                         y,
                     );
-                    if self.get(reflection_x - offset - 1, y)
-                        != self.get(reflection_x + offset, y)
+                    if self.get(reflection_x - offset - 1, y) != self.get(reflection_x + offset, y)
                     {
                         dprintln!("    Not a reflection.");
                         errors += 1;
@@ -278,8 +276,7 @@ impl Pattern {
                         x,
                         reflection_y + offset
                     );
-                    if self.get(x, reflection_y - offset - 1)
-                        != self.get(x, reflection_y + offset)
+                    if self.get(x, reflection_y - offset - 1) != self.get(x, reflection_y + offset)
                     {
                         dprintln!("    Not a reflection.");
                         errors += 1;
@@ -298,48 +295,59 @@ impl Pattern {
             }
         }
 
-        panic!("Pattern without Reflection.");
-        //None
+        None
     }
 }
 
 type Data = Vec<Pattern>;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test13.txt"))
-    }
-
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(405), Answer::Number(0))
+    const TITLE: Option<&'static str> = Some("Point of Incidence");
+    const TAGS: &'static [&'static str] = &["grid"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test13.txt"),
+            one: Some(Answer::Number(405)),
+            two: Some(Answer::Number(400)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.split("\n\n").map(|v| v.into()).collect())
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, parse_blocks(input, Pattern::try_from)?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         dprintln!("{:?}", data);
 
-        Answer::Number(
+        Ok(Answer::Number(
             data.iter()
-                .map(|p| match p.get_reflection_line() {
-                    Some(Reflection::OnXAxis(x, _)) => x,
-                    Some(Reflection::OnYAxis(y, _)) => 100 * y,
-                    None => panic!(),
+                .enumerate()
+                .map(|(i, p)| match p.get_reflection_line() {
+                    Some(Reflection::OnXAxis(x, _)) => Ok(x),
+                    Some(Reflection::OnYAxis(y, _)) => Ok(100 * y),
+                    None => Err(SolveError::unsolvable(format!(
+                        "pattern {} has no reflection",
+                        i + 1
+                    ))),
                 })
-                .sum::<usize>() as u64,
-        )
+                .sum::<Result<usize, _>>()? as u64,
+        ))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
-        Answer::Number(
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        Ok(Answer::Number(
             data.iter()
-                .map(|p| match p.get_corrected_reflection_line() {
-                    Some(Reflection::OnXAxis(x, _)) => x,
-                    Some(Reflection::OnYAxis(y, _)) => 100 * y,
-                    None => panic!(),
+                .enumerate()
+                .map(|(i, p)| match p.get_corrected_reflection_line() {
+                    Some(Reflection::OnXAxis(x, _)) => Ok(x),
+                    Some(Reflection::OnYAxis(y, _)) => Ok(100 * y),
+                    None => Err(SolveError::unsolvable(format!(
+                        "pattern {} has no reflection",
+                        i + 1
+                    ))),
                 })
-                .sum::<usize>() as u64,
-        )
+                .sum::<Result<usize, _>>()? as u64,
+        ))
     }
 }
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::{dprintln, vprintln};
 
-use super::{utils::Direction, Answer, Day, DayImpl};
+use super::{utils::Direction, Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 14;
 
@@ -13,13 +13,15 @@ pub enum Tile {
     Empty,
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = SolveError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '#' => Self::CubeRock,
-            'O' => Self::RoundRock,
-            '.' => Self::Empty,
-            _ => panic!("Invalid input"),
+            '#' => Ok(Self::CubeRock),
+            'O' => Ok(Self::RoundRock),
+            '.' => Ok(Self::Empty),
+            _ => Err(SolveError::parse(format!("invalid tile '{}'", value))),
         }
     }
 }
@@ -161,19 +163,23 @@ impl Map {
 
 type Data = Map;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test14.txt"))
-    }
-
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(136), Answer::Number(64))
+    const TITLE: Option<&'static str> = Some("Parabolic Reflector Dish");
+    const TAGS: &'static [&'static str] = &["grid", "simulation", "cycle-detection"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test14.txt"),
+            one: Some(Answer::Number(136)),
+            two: Some(Answer::Number(64)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.into())
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, input.try_into()?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         #[cfg(debug_assertions)]
         data.print();
 
@@ -187,10 +193,10 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
         #[cfg(debug_assertions)]
         data.print();
 
-        Answer::Number(data.get_load() as u64)
+        Ok(Answer::Number(data.get_load() as u64))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut previous_by_map: HashMap<Vec<Vec<Tile>>, usize> = HashMap::new();
         let mut previous_by_index: HashMap<usize, Vec<Vec<Tile>>> = HashMap::new();
 
@@ -198,7 +204,7 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             if let Some(prev_index) = previous_by_map.get(data.get_raw_tiles()) {
                 let loop_length = i - prev_index;
                 if (1000000000 - i) % loop_length == 0 {
-                    return Answer::Number(data.get_load() as u64);
+                    return Ok(Answer::Number(data.get_load() as u64));
                 }
             }
 
@@ -213,6 +219,6 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             previous_by_index.insert(i, beginning_map);
         }
 
-        Answer::Number(data.get_load() as u64)
+        Ok(Answer::Number(data.get_load() as u64))
     }
 }
@@ -1,29 +1,35 @@
-use super::{Answer, Day, DayImpl};
+use super::{parse_number, Answer, Day, DayImpl, SolveError, TestCase};
 
 const CURRENT_DAY: u8 = 15;
 
 type Data = Vec<String>;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test15.txt"))
-    }
+    const TITLE: Option<&'static str> = Some("Lens Library");
+    const TAGS: &'static [&'static str] = &["hashing"];
 
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(1320), Answer::Number(145))
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test15.txt"),
+            one: Some(Answer::Number(1320)),
+            two: Some(Answer::Number(145)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((
             Self {},
             input.trim_end().split(',').map(|v| v.to_owned()).collect(),
-        )
+        ))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
-        Answer::Number(data.iter().map(|v| Self::hash_string(v)).sum::<usize>() as u64)
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        Ok(Answer::Number(
+            data.iter().map(|v| Self::hash_string(v)).sum::<usize>() as u64,
+        ))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut boxes: Vec<Vec<(&str, usize)>> = Vec::with_capacity(256);
 
         for _ in 0..256 {
@@ -31,16 +37,20 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
         }
 
         for step in data {
-            if step.ends_with('-') {
-                let label = step.split_once('-').unwrap().0;
+            if let Some(label) = step.strip_suffix('-') {
                 let hash = Self::hash_string(label);
 
                 if let Some(index) = boxes[hash].iter().position(|v| v.0 == label) {
                     boxes[hash].remove(index);
                 }
             } else {
-                let (label, num_str) = step.split_once('=').unwrap();
-                let num = num_str.parse::<usize>().unwrap();
+                let (label, num_str) = step.split_once('=').ok_or_else(|| {
+                    SolveError::parse(format!(
+                        "expected '<label>=<focal length>', found '{}'",
+                        step
+                    ))
+                })?;
+                let num = parse_number(num_str)?;
                 let hash = Self::hash_string(label);
 
                 if let Some(index) = boxes[hash].iter().position(|v| v.0 == label) {
@@ -51,7 +61,7 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             }
         }
 
-        Answer::Number(
+        Ok(Answer::Number(
             boxes
                 .iter()
                 .enumerate()
@@ -62,7 +72,7 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
                         .sum::<usize>()
                 })
                 .sum::<usize>() as u64,
-        )
+        ))
     }
 }
 
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use super::{
     utils::{Direction, Map},
-    Answer, Day, DayImpl,
+    Answer, Day, DayImpl, SolveError, TestCase,
 };
 
 const CURRENT_DAY: u8 = 16;
@@ -29,15 +29,17 @@ impl Mirror {
     }
 }
 
-impl From<char> for Mirror {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Mirror {
+    type Error = SolveError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '|' => Self::Vertical,
-            '-' => Self::Horizontal,
-            '/' => Self::DiagonalTopRight,
-            '\\' => Self::DiagonalTopLeft,
-            '.' => Self::None,
-            _ => panic!("Invalid Input"),
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            '/' => Ok(Self::DiagonalTopRight),
+            '\\' => Ok(Self::DiagonalTopLeft),
+            '.' => Ok(Self::None),
+            _ => Err(SolveError::parse(format!("invalid tile '{}'", value))),
         }
     }
 }
@@ -198,34 +200,38 @@ impl Data {
 }
 
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test16.txt"))
-    }
-
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(46), Answer::Number(51))
+    const TITLE: Option<&'static str> = Some("The Floor Will Be Lava");
+    const TAGS: &'static [&'static str] = &["grid", "simulation"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test16.txt"),
+            one: Some(Answer::Number(46)),
+            two: Some(Answer::Number(51)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.into())
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, input.try_into()?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let energized = data.start_energize(0, 0, Direction::East);
 
         #[cfg(debug_assertions)]
         data.print(&energized);
 
-        Answer::Number(
+        Ok(Answer::Number(
             energized
                 .iter()
                 .map(|v| (v.0, v.1))
                 .collect::<HashSet<(usize, usize)>>()
                 .len() as u64,
-        )
+        ))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let mut max = 0;
         for x in 0..data.dimensions().0 {
             max = max.max(data.start_energize(x, 0, Direction::South).len());
@@ -242,6 +248,6 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
                     .len(),
             );
         }
-        Answer::Number(max as u64)
+        Ok(Answer::Number(max as u64))
     }
 }
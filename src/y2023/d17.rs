@@ -9,7 +9,7 @@ use crate::dprintln;
 
 use super::{
     utils::{Direction, Map},
-    Answer, Day, DayImpl,
+    Answer, Day, DayImpl, SolveError, TestCase,
 };
 
 const CURRENT_DAY: u8 = 17;
@@ -46,11 +46,15 @@ pub struct Block {
     heat_loss: u8,
 }
 
-impl From<char> for Block {
-    fn from(value: char) -> Self {
-        Self {
-            heat_loss: value.to_digit(10).unwrap() as u8,
-        }
+impl TryFrom<char> for Block {
+    type Error = SolveError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(Self {
+            heat_loss: value.to_digit(10).ok_or_else(|| {
+                SolveError::parse(format!("expected a heat loss digit, found '{}'", value))
+            })? as u8,
+        })
     }
 }
 
@@ -67,7 +71,7 @@ impl std::cmp::Ord for APos {
 
 impl PartialOrd for APos {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(other.4.cmp(&self.4))
+        Some(self.cmp(other))
     }
 }
 
@@ -408,26 +412,30 @@ impl Data {
 }
 
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test17.txt"))
-    }
-
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(102), Answer::Number(94))
+    const TITLE: Option<&'static str> = Some("Clumsy Crucible");
+    const TAGS: &'static [&'static str] = &["grid", "pathfinding"];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test17.txt"),
+            one: Some(Answer::Number(102)),
+            two: Some(Answer::Number(94)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.into())
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, input.try_into()?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         dprintln!("{:?}", data);
         let path = data
             .modified_a_star_a(
                 APos(0, 0, Direction::East, 0, 0),
                 (data.dimensions().0 - 1, data.dimensions().1 - 1),
             )
-            .unwrap();
+            .ok_or_else(|| SolveError::unsolvable("there is no path to the factory"))?;
         dprintln!("{:?}", path);
 
         #[cfg(debug_assertions)]
@@ -450,18 +458,20 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             vprintln!();
         }
 
-        Answer::Number(path.iter().rev().skip(1).fold(0, |acc, pos| {
-            acc + data.get(pos.0, pos.1).unwrap().heat_loss as u64
-        }))
+        Ok(Answer::Number(
+            path.iter().rev().skip(1).fold(0, |acc, pos| {
+                acc + data.get(pos.0, pos.1).unwrap().heat_loss as u64
+            }),
+        ))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let path = data
             .modified_a_star_b(
                 APos(0, 0, Direction::East, 0, 0),
                 (data.dimensions().0 - 1, data.dimensions().1 - 1),
             )
-            .unwrap();
+            .ok_or_else(|| SolveError::unsolvable("there is no path to the factory"))?;
         dprintln!("{:?}", path);
 
         println!();
@@ -485,8 +495,10 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
             vprintln!();
         }
 
-        Answer::Number(path.iter().rev().skip(1).fold(0, |acc, pos| {
-            acc + data.get(pos.0, pos.1).unwrap().heat_loss as u64
-        }))
+        Ok(Answer::Number(
+            path.iter().rev().skip(1).fold(0, |acc, pos| {
+                acc + data.get(pos.0, pos.1).unwrap().heat_loss as u64
+            }),
+        ))
     }
 }
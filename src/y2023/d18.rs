@@ -2,7 +2,10 @@ use std::collections::{HashMap, VecDeque};
 
 use crate::{dprintln, vprint, vprintln};
 
-use super::{utils::Direction, Answer, Day, DayImpl};
+use super::{
+    parse_lines, parse_number, utils::Direction, Answer, Day, DayImpl, PartStatus, SolveError,
+    TestCase,
+};
 
 const CURRENT_DAY: u8 = 18;
 
@@ -13,46 +16,58 @@ pub struct Instruction {
     colour_encoded: Option<(usize, Direction)>,
 }
 
-impl From<&str> for Instruction {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for Instruction {
+    type Error = SolveError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut split = value.split(' ');
-        Self {
-            direction: match split.next().unwrap() {
-                "U" => Direction::North,
-                "R" => Direction::East,
-                "D" => Direction::South,
-                "L" => Direction::West,
-                _ => panic!(),
-            },
-            count: split.next().unwrap().parse().unwrap(),
+        let direction = match split.next() {
+            Some("U") => Direction::North,
+            Some("R") => Direction::East,
+            Some("D") => Direction::South,
+            Some("L") => Direction::West,
+            other => {
+                return Err(SolveError::parse(format!(
+                    "expected a direction, found '{}'",
+                    other.unwrap_or_default()
+                )))
+            }
+        };
+        let count = parse_number(
+            split
+                .next()
+                .ok_or_else(|| SolveError::parse("missing count"))?,
+        )?;
+
+        let colour = split
+            .next()
+            .and_then(|v| v.strip_prefix("(#"))
+            .and_then(|v| v.strip_suffix(')'))
+            .filter(|v| v.len() == 6 && v.is_ascii())
+            .ok_or_else(|| SolveError::parse("expected a colour like '(#70c710)'"))?;
+        let (encoded_count, encoded_direction) = colour.split_at(5);
+
+        Ok(Self {
+            direction,
+            count,
             colour_encoded: Some((
-                usize::from_str_radix(
-                    split
-                        .clone()
-                        .peekable()
-                        .peek()
-                        .unwrap()
-                        .strip_prefix("(#")
-                        .unwrap()
-                        .strip_suffix(')')
-                        .unwrap()
-                        .strip_suffix([
-                            'A', 'B', 'C', 'D', 'E', 'F', '0', '1', '2', '3', '4', '5', '6', '7',
-                            '8', '9',
-                        ])
-                        .unwrap(),
-                    16,
-                )
-                .unwrap(),
-                match split.next().unwrap().chars().rev().nth(1).unwrap() {
-                    '0' => Direction::East,
-                    '1' => Direction::South,
-                    '2' => Direction::West,
-                    '3' => Direction::North,
-                    _ => panic!(),
+                usize::from_str_radix(encoded_count, 16).map_err(|_| {
+                    SolveError::parse(format!("invalid hexadecimal count '{}'", encoded_count))
+                })?,
+                match encoded_direction {
+                    "0" => Direction::East,
+                    "1" => Direction::South,
+                    "2" => Direction::West,
+                    "3" => Direction::North,
+                    _ => {
+                        return Err(SolveError::parse(format!(
+                            "invalid encoded direction '{}'",
+                            encoded_direction
+                        )))
+                    }
                 },
             )),
-        }
+        })
     }
 }
 
@@ -184,19 +199,25 @@ impl Map {
 
 type Data = Vec<Instruction>;
 impl DayImpl<Data> for Day<CURRENT_DAY> {
-    fn init_test() -> (Self, Data) {
-        Self::init(include_str!("test_inputs/test18.txt"))
-    }
-
-    fn expected_results() -> (Answer, Answer) {
-        (Answer::Number(62), Answer::Number(952408144115))
+    const TITLE: Option<&'static str> = Some("Lavaduct Lagoon");
+    const TAGS: &'static [&'static str] = &["grid", "geometry"];
+    // Part two fills the whole area, which is far too slow for the real input.
+    const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::InProgress];
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test18.txt"),
+            one: Some(Answer::Number(62)),
+            two: Some(Answer::Number(952408144115)),
+        }]
     }
 
-    fn init(input: &str) -> (Self, Data) {
-        (Self {}, input.lines().map(|v| v.into()).collect())
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, parse_lines(input, Instruction::try_from)?))
     }
 
-    fn one(&self, data: &mut Data) -> Answer {
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
         dprintln!("{:?}", data);
         let mut map = Map::default();
         map.execute_instructions(data, (0, 0));
@@ -205,10 +226,10 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
         map.fill_surrounded();
         map.print();
 
-        Answer::Number(map.count_points() as u64)
+        Ok(Answer::Number(map.count_points() as u64))
     }
 
-    fn two(&self, data: &mut Data) -> Answer {
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
         let new_instructions: Vec<Instruction> = data
             .iter()
             .map(|v| Instruction {
@@ -222,6 +243,6 @@ impl DayImpl<Data> for Day<CURRENT_DAY> {
         map.execute_instructions(&new_instructions, (0, 0));
         map.fill_surrounded();
 
-        Answer::Number(map.count_points() as u64)
+        Ok(Answer::Number(map.count_points() as u64))
     }
 }
@@ -0,0 +1,29 @@
+use super::{parse_lines, parse_number, Answer, Day, DayImpl, SolveError, TestCase};
+
+const CURRENT_DAY: u8 = 20;
+
+type Data = Vec<u64>;
+impl DayImpl<Data> for Day<CURRENT_DAY> {
+    const IMPLEMENTED: bool = false;
+
+    fn test_cases() -> Vec<TestCase> {
+        vec![TestCase {
+            name: "example",
+            input: include_str!("test_inputs/test20.txt"),
+            one: None,
+            two: None,
+        }]
+    }
+
+    fn init(input: &str) -> Result<(Self, Data), SolveError> {
+        Ok((Self {}, parse_lines(input, parse_number)?))
+    }
+
+    fn one(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        Ok(Answer::Number(data.len() as u64))
+    }
+
+    fn two(&self, data: &mut Data) -> Result<Answer, SolveError> {
+        Ok(Answer::Number(data.len() as u64))
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_macro::{day_tests, days};
+
+pub use crate::utils;
+pub use aoc_framework::{
+    parse_blocks, parse_lines, parse_number, Answer, DayImpl, DynDay, Event, PartStatus,
+    Registered, SolveError, TestCase,
+};
+
+pub struct Day<const DAY: u8>;
+
+days!(
+    d01, d02, d03, d04, d05, d06, d07, d08, d09, d10, d11, d12, d13, d14, d15, d16, d17, d18, d19,
+    d20, d21, d22, d23, d24, d25
+);
+
+pub static EVENT: Event = Event::new(2023, &DAYS);
+
+// Days which are still the template can't pass their tests, and day 18's part 2
+// doesn't finish in reasonable time on the example.
+day_tests!(day18_part2, day19, day20, day21, day22, day23, day24, day25);
+
+#[cfg(test)]
+mod malformed_inputs {
+    use super::*;
+    use aoc_framework::Part;
+
+    #[test]
+    fn malformed_inputs_are_errors() {
+        let cases: &[(u8, &str)] = &[
+            (3, "99999999999*"),
+            (4, ""),
+            (4, "Card 1: 1 2 | 1 2"),
+            (4, "Card 1: 1 | 2\nCard 3: 1 | 2"),
+            (8, "L\n\nAAA = (AAA, AAA)"),
+            (9, "0 3 6\n\n1 2 3"),
+            (9, "-9223372036854775808 9223372036854775807"),
+            (9, "9223372036854775807 0 0"),
+            (10, "S-7\n|"),
+            (14, "#.#\n.#"),
+        ];
+        for (day, input) in cases {
+            let solved = EVENT.get_day(*day).unwrap().solve(&Part::Both, input);
+            assert!(solved.is_err(), "day {} solved {:?}", day, input);
+        }
+        // A start tile without a loop is valid, but mustn't walk off the map.
+        assert!(EVENT.get_day(10).unwrap().solve(&Part::Both, "S").is_ok());
+    }
+
+    #[test]
+    fn unbeatable_races_have_no_wins() {
+        let solved = EVENT
+            .get_day(6)
+            .unwrap()
+            .solve(&Part::Both, "Time: 1 2\nDistance: 5 9")
+            .unwrap();
+        assert_eq!(solved.0, Some(Answer::Number(0)));
+        assert_eq!(solved.1, Some(Answer::Number(0)));
+    }
+}